@@ -0,0 +1,62 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use anyhow::{ensure, Result};
+
+use crate::Solution;
+
+/// Spread of wall times over repeated runs of one phase.
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        Self {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Timings {
+    pub runs: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+/// Times parsing and both parts separately, `runs` times over.
+pub fn bench<S: Solution>(raw: &str, runs: usize) -> Result<Timings> {
+    ensure!(runs > 0, "need at least one run");
+    let mut parse = Vec::with_capacity(runs);
+    let mut part1 = Vec::with_capacity(runs);
+    let mut part2 = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let start = Instant::now();
+        let input = black_box(S::parse(raw)?);
+        parse.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part1(&input)?);
+        part1.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part2(&input)?);
+        part2.push(start.elapsed());
+    }
+    Ok(Timings {
+        runs,
+        parse: Stats::from_samples(parse),
+        part1: Stats::from_samples(part1),
+        part2: Stats::from_samples(part2),
+    })
+}
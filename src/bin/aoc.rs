@@ -0,0 +1,335 @@
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    thread,
+};
+
+use anyhow::{bail, ensure, Context, Result};
+use aoc2023::{
+    bench::Stats,
+    error::ErrorKind,
+    find_day,
+    runner::{self, DayReport, Report},
+    source,
+    verify::{self, Check},
+    Answer, DAYS,
+};
+use serde_json::{json, Value};
+
+const USAGE: &str = "usage:
+  aoc <day|from-to|all> [1|2] [--input <file|dir|->] [--format table|json] [--threads <n>]
+  aoc verify [day|from-to|all] [--answers <file>]
+  aoc bench <day|from-to|all> [--runs <n>] [--input <dir>]";
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("{e:?}");
+            eprintln!("{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+struct Args {
+    positional: Vec<String>,
+    input: Option<String>,
+    answers: Option<PathBuf>,
+    runs: usize,
+    threads: usize,
+    format: Format,
+    help: bool,
+}
+
+#[derive(PartialEq, Eq)]
+enum Format {
+    Table,
+    Json,
+}
+
+impl Args {
+    fn parse() -> Result<Self> {
+        let mut parsed = Self {
+            positional: vec![],
+            input: None,
+            answers: None,
+            runs: 10,
+            threads: thread::available_parallelism().map_or(1, |n| n.get()),
+            format: Format::Table,
+            help: false,
+        };
+        let mut args = env::args().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--help" | "-h" => parsed.help = true,
+                "--input" | "-i" => {
+                    parsed.input = Some(args.next().context("--input needs a path")?)
+                }
+                "--answers" => {
+                    parsed.answers = Some(args.next().context("--answers needs a path")?.into())
+                }
+                "--runs" => {
+                    parsed.runs = args
+                        .next()
+                        .context("--runs needs a count")?
+                        .parse()
+                        .context("--runs should be a number")?
+                }
+                "--threads" => {
+                    parsed.threads = args
+                        .next()
+                        .context("--threads needs a count")?
+                        .parse()
+                        .context("--threads should be a number")?
+                }
+                "--format" => {
+                    parsed.format = match args.next().as_deref() {
+                        Some("table") => Format::Table,
+                        Some("json") => Format::Json,
+                        f => bail!("--format should be table or json, got {f:?}"),
+                    }
+                }
+                _ => parsed.positional.push(arg),
+            }
+        }
+        Ok(parsed)
+    }
+}
+
+fn run() -> Result<ExitCode> {
+    let args = Args::parse()?;
+    if args.help {
+        println!("{USAGE}");
+        return Ok(ExitCode::SUCCESS);
+    }
+    match args.positional.first().map(String::as_str) {
+        Some("verify") => verify_days(&args),
+        Some("bench") => bench_days(&args).map(|_| ExitCode::SUCCESS),
+        _ => solve_days(&args),
+    }
+}
+
+fn solve_days(args: &Args) -> Result<ExitCode> {
+    let mut positional = args.positional.iter();
+    let days = parse_days(positional.next().context("missing day")?)?;
+    let selected_parts = match positional.next().map(String::as_str) {
+        None => vec![1, 2],
+        Some("1") => vec![1],
+        Some("2") => vec![2],
+        Some(p) => bail!("unknown part {p}"),
+    };
+    if let Some(input) = &args.input {
+        ensure!(
+            days.len() == 1 || Path::new(input).is_dir(),
+            "a single input file can only be used with a single day"
+        );
+    }
+
+    let report = runner::run_days(&days, args.input.as_deref(), &selected_parts, args.threads);
+    match args.format {
+        Format::Table => print_report(&report),
+        Format::Json => println!("{:#}", report_json(&report)),
+    }
+    if report.failures() > 0 {
+        Ok(ExitCode::FAILURE)
+    } else {
+        Ok(ExitCode::SUCCESS)
+    }
+}
+
+fn verify_days(args: &Args) -> Result<ExitCode> {
+    let days = match args.positional.get(1) {
+        Some(days) => parse_days(days)?,
+        None => DAYS.iter().map(|d| d.day).collect(),
+    };
+    let path = match &args.answers {
+        Some(path) => path.clone(),
+        None => default_answers(),
+    };
+    let expected = verify::load_answers(&path)?
+        .into_iter()
+        .filter(|e| days.contains(&e.day))
+        .collect::<Vec<_>>();
+    let checks = verify::verify(&expected);
+    print_checks(&checks);
+    Ok(ExitCode::from(verify::exit_code(&checks)))
+}
+
+fn bench_days(args: &Args) -> Result<()> {
+    let days = parse_days(args.positional.get(1).context("missing day")?)?;
+    if let Some(input) = &args.input {
+        ensure!(
+            days.len() == 1 || Path::new(input).is_dir(),
+            "a single input file can only be used with a single day"
+        );
+    }
+    println!(
+        "day | phase  | {:>10} | {:>10} | {:>10}",
+        "min", "median", "max"
+    );
+    println!("----+--------+-{0}-+-{0}-+-{0}", "-".repeat(10));
+    for day in days {
+        let solver = find_day(day).with_context(|| format!("no solution for day {day}"))?;
+        let input = source::load(day, args.input.as_deref())?;
+        let timings =
+            (solver.bench)(&input.raw, args.runs).with_context(|| format!("day {day} failed"))?;
+        for (phase, stats) in [
+            ("parse", timings.parse),
+            ("part 1", timings.part1),
+            ("part 2", timings.part2),
+        ] {
+            print_stats(day, phase, &stats);
+        }
+    }
+    Ok(())
+}
+
+fn print_stats(day: u8, phase: &str, stats: &Stats) {
+    println!(
+        " {day:02} | {phase:<6} | {:>10} | {:>10} | {:>10}",
+        format!("{:.2?}", stats.min),
+        format!("{:.2?}", stats.median),
+        format!("{:.2?}", stats.max),
+    );
+}
+
+fn default_answers() -> PathBuf {
+    let local = PathBuf::from("answers.toml");
+    if local.is_file() {
+        local
+    } else {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("answers.toml")
+    }
+}
+
+fn parse_days(raw: &str) -> Result<Vec<u8>> {
+    if raw == "all" {
+        return Ok(DAYS.iter().map(|d| d.day).collect());
+    }
+    let days = match raw.split_once('-') {
+        Some((from, to)) => {
+            let from = from.parse::<u8>().context("invalid range start")?;
+            let to = to.parse::<u8>().context("invalid range end")?;
+            ensure!(from <= to, "day range {raw} runs backwards");
+            (from..=to).collect()
+        }
+        None => vec![raw.parse::<u8>().context("invalid day")?],
+    };
+    Ok(days)
+}
+
+fn print_report(report: &Report) {
+    let width = report
+        .days
+        .iter()
+        .filter_map(|d| d.result.as_ref().ok())
+        .flat_map(|(_, solved)| &solved.parts)
+        .map(|p| p.answer.to_string().len())
+        .chain(["answer".len()])
+        .max()
+        .unwrap_or_default();
+    println!("day | part | {:>10} | answer", "time");
+    println!("----+------+-{}-+-{}", "-".repeat(10), "-".repeat(width));
+    for DayReport {
+        day,
+        result,
+        elapsed,
+    } in &report.days
+    {
+        match result {
+            Ok((_, solved)) => {
+                for p in &solved.parts {
+                    let time = format!("{:.2?}", p.time);
+                    println!(" {day:02} | {:>4} | {time:>10} | {}", p.part, p.answer);
+                }
+            }
+            Err(e) => {
+                let time = format!("{elapsed:.2?}");
+                println!(
+                    " {day:02} |    - | {time:>10} | {}: {e:#}",
+                    ErrorKind::of(e)
+                )
+            }
+        }
+    }
+    println!(
+        "{} days, {} failed, {:.2?} wall time ({:.2?} across threads)",
+        report.days.len(),
+        report.failures(),
+        report.wall_time,
+        report.cpu_time()
+    );
+}
+
+fn report_json(report: &Report) -> Value {
+    report
+        .days
+        .iter()
+        .flat_map(|DayReport { day, result, .. }| -> Vec<Value> {
+            let (source, solved) = match result {
+                Ok(ok) => ok,
+                Err(e) => {
+                    let kind = match ErrorKind::of(e) {
+                        ErrorKind::Parse => "parse",
+                        ErrorKind::NoSolution => "no_solution",
+                        ErrorKind::Invariant => "invariant",
+                        ErrorKind::Other => "other",
+                    };
+                    return vec![json!({
+                        "day": day,
+                        "error": format!("{e:#}"),
+                        "error_kind": kind,
+                    })];
+                }
+            };
+            solved
+                .parts
+                .iter()
+                .map(move |p| {
+                    let (kind, answer) = match &p.answer {
+                        Answer::Signed(n) => ("signed", json!(n)),
+                        Answer::Unsigned(n) => ("unsigned", json!(n)),
+                        Answer::Text(s) => ("text", json!(s)),
+                    };
+                    json!({
+                        "day": day,
+                        "part": p.part,
+                        "answer": answer,
+                        "answer_type": kind,
+                        "input": source.to_string(),
+                        "parse_seconds": solved.parse_time.as_secs_f64(),
+                        "solve_seconds": p.time.as_secs_f64(),
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn print_checks(checks: &[Check]) {
+    let width = checks
+        .iter()
+        .map(|c| c.expected.input.display().to_string().len())
+        .chain(["input".len()])
+        .max()
+        .unwrap_or_default();
+    println!("day | part | {:<width$} | result", "input");
+    println!("----+------+-{}-+-------", "-".repeat(width));
+    for check in checks {
+        let expected = &check.expected;
+        println!(
+            " {:02} | {:>4} | {:<width$} | {}",
+            expected.day,
+            expected.part,
+            expected.input.display(),
+            check.outcome
+        );
+    }
+    let passed = checks
+        .iter()
+        .filter(|c| matches!(c.outcome, verify::Outcome::Pass))
+        .count();
+    println!("{passed}/{} passed", checks.len());
+}
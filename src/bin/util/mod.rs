@@ -1,2 +0,0 @@
-pub(crate) mod direction;
-pub(crate) mod bidimap;
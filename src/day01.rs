@@ -0,0 +1,84 @@
+use anyhow::Result;
+
+use crate::{error::ParseError, util::input, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = String;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(raw: &str) -> Result<Self::Input> {
+        Ok(raw.trim().to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<u64> {
+        Ok(sum_of_calibrations(input)?)
+    }
+
+    fn part2(input: &Self::Input) -> Result<u64> {
+        Ok(sum_of_calibrations_spelled_out(input)?)
+    }
+}
+
+fn sum_of_calibrations(content: &str) -> Result<u64, ParseError> {
+    input::lines(content)
+        .map(|line| calibration_value(line).map_err(|e| e.rebase(content, line)))
+        .sum()
+}
+
+fn calibration_value(line: &str) -> Result<u64, ParseError> {
+    let mut digits = line.chars().filter_map(|c| c.to_digit(10));
+    let first = digits
+        .next()
+        .ok_or_else(|| ParseError::new(line, line, "at least one digit"))?;
+    let last = digits.next_back().unwrap_or(first);
+    Ok((first * 10 + last) as u64)
+}
+
+fn sum_of_calibrations_spelled_out(content: &str) -> Result<u64, ParseError> {
+    input::lines(content)
+        .map(|line| calibration_value_spelled_out(line).map_err(|e| e.rebase(content, line)))
+        .sum()
+}
+
+fn calibration_value_spelled_out(line: &str) -> Result<u64, ParseError> {
+    let values = [
+        "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+
+    let mut first_digit = 0;
+    let mut first_digit_idx = usize::MAX;
+    let mut last_digit = 0;
+    let mut last_digit_idx = 0;
+
+    for (i, value) in values.iter().enumerate() {
+        let num_val = format!("{i}");
+        let search_strings = [num_val.as_str(), value];
+        for val in search_strings {
+            if let Some(idx) = line.find(val) {
+                if idx <= first_digit_idx {
+                    first_digit = i;
+                    first_digit_idx = idx;
+                }
+            }
+            if let Some(idx) = line.rfind(val) {
+                if idx >= last_digit_idx {
+                    last_digit = i;
+                    last_digit_idx = idx;
+                }
+            }
+        }
+    }
+    if first_digit_idx == usize::MAX {
+        return Err(ParseError::new(
+            line,
+            line,
+            "at least one digit, numeric or spelled out",
+        ));
+    }
+    Ok((first_digit * 10 + last_digit) as u64)
+}
@@ -0,0 +1,127 @@
+use std::{cmp::max, str::FromStr};
+
+use anyhow::{Context, Result};
+
+use crate::{
+    error::{parse_lines, parse_split, ParseError},
+    util::input,
+    Solution,
+};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(raw: &str) -> Result<Self::Input> {
+        parse_lines(raw.trim()).context("failed to make games")
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        Ok(possible_games(input))
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        Ok(minimum_cubes(input))
+    }
+}
+
+struct GamePick {
+    red: usize,
+    blue: usize,
+    green: usize,
+}
+
+impl GamePick {
+    fn is_possible(&self, pick: &Self) -> bool {
+        self.red <= pick.red && self.green <= pick.green && self.blue <= pick.blue
+    }
+
+    fn power(&self) -> usize {
+        self.red * self.green * self.blue
+    }
+}
+
+impl FromStr for GamePick {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let colors = s.split(", ");
+        let mut red = 0;
+        let mut blue = 0;
+        let mut green = 0;
+        for pair in colors {
+            let (number, color) = pair
+                .split_once(' ')
+                .ok_or_else(|| ParseError::new(s, pair, "a count and a color"))?;
+            let number = number
+                .parse::<usize>()
+                .map_err(|_| ParseError::new(s, number, "a number"))?;
+            match color {
+                "red" => red += number,
+                "blue" => blue += number,
+                "green" => green += number,
+                _ => return Err(ParseError::new(s, color, "red, green or blue")),
+            }
+        }
+        Ok(Self { red, blue, green })
+    }
+}
+
+pub struct Game {
+    id: usize,
+    picks: Vec<GamePick>,
+}
+
+impl Game {
+    fn is_possible(&self, pick: &GamePick) -> bool {
+        self.picks.iter().all(|p| p.is_possible(pick))
+    }
+
+    fn min_pick(&self) -> GamePick {
+        let (red, green, blue) = self.picks.iter().fold((0, 0, 0), |(r, g, b), pick| {
+            (max(r, pick.red), max(g, pick.green), max(b, pick.blue))
+        });
+        GamePick { red, green, blue }
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (game, picks) = input::key_value(s)?;
+        let id = input::labelled(game, "Game ").map_err(|e| e.rebase(s, game))?;
+        let id = id
+            .parse::<usize>()
+            .map_err(|_| ParseError::new(s, id, "a game id"))?;
+        let picks = parse_split(picks, "; ").map_err(|e| e.rebase(s, picks))?;
+        Ok(Self { id, picks })
+    }
+}
+
+fn possible_games(games: &[Game]) -> usize {
+    let all_cubes = GamePick {
+        red: 12,
+        blue: 14,
+        green: 13,
+    };
+    games
+        .iter()
+        .filter_map(|g| {
+            if g.is_possible(&all_cubes) {
+                Some(g.id)
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+fn minimum_cubes(games: &[Game]) -> usize {
+    games.iter().map(Game::min_pick).map(|gp| gp.power()).sum()
+}
@@ -0,0 +1,129 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    str::FromStr,
+};
+
+use anyhow::Result;
+
+use crate::{
+    error::ParseError,
+    util::{direction::Direction8, input},
+    Solution,
+};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Schematic;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(raw: &str) -> Result<Self::Input> {
+        Ok(Schematic::from_str(raw.trim())?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<u64> {
+        let (_, sum) = input.connections_and_sum();
+        Ok(sum)
+    }
+
+    fn part2(input: &Self::Input) -> Result<u64> {
+        let (connections, _) = input.connections_and_sum();
+        Ok(input.gear_ratios(&connections))
+    }
+}
+
+#[derive(Debug)]
+pub struct Schematic {
+    numbers: BTreeMap<(isize, isize), u64>,
+    symbols: BTreeMap<(isize, isize), char>,
+}
+
+type Connections = BTreeMap<(isize, isize), HashSet<u64>>;
+
+impl FromStr for Schematic {
+    type Err = ParseError;
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        let mut numbers = BTreeMap::new();
+        let mut symbols = BTreeMap::new();
+
+        for (x, row) in input::lines(raw).enumerate() {
+            for (y, c) in row.chars().enumerate() {
+                match c {
+                    '.' => {}
+                    c if c.is_ascii_digit() => {
+                        let n = c
+                            .to_digit(10)
+                            .ok_or_else(|| ParseError::at(row, y, "a digit").rebase(raw, row))?;
+                        numbers.insert((x as isize, y as isize), n as u64);
+                    }
+                    c => {
+                        symbols.insert((x as isize, y as isize), c);
+                    }
+                }
+            }
+        }
+        Ok(Self { numbers, symbols })
+    }
+}
+
+impl Schematic {
+    fn connections_and_sum(&self) -> (Connections, u64) {
+        let mut total = 0;
+        let mut current_number = None;
+        let mut connections = Connections::new();
+        // going from left to right since our keys are ordered.
+        for ((x, y), n) in &self.numbers {
+            let adjacent_symbols = self.adjacent_symbols(*x, *y);
+            current_number = match current_number {
+                None => Some(((*x, *y), *n, adjacent_symbols)),
+                Some(((cx, cy), cn, mut cas)) if cx == *x && cy == *y - 1 => {
+                    cas.extend(adjacent_symbols);
+                    Some(((*x, *y), cn * 10 + n, cas))
+                }
+                Some((_, cn, cas)) => {
+                    if !cas.is_empty() {
+                        total += cn;
+                    }
+                    for (sx, sy) in cas {
+                        connections.entry((sx, sy)).or_default().insert(cn);
+                    }
+                    Some(((*x, *y), *n, adjacent_symbols))
+                }
+            }
+        }
+        if let Some((_, cn, cas)) = current_number {
+            if !cas.is_empty() {
+                total += cn;
+            }
+            for (sx, sy) in cas {
+                connections.entry((sx, sy)).or_default().insert(cn);
+            }
+        }
+        (connections, total)
+    }
+
+    fn adjacent_symbols(&self, x: isize, y: isize) -> Vec<(isize, isize)> {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(|d| d.step((x, y)))
+            .filter(|k| self.symbols.contains_key(k))
+            .collect()
+    }
+
+    fn gear_ratios(&self, connections: &Connections) -> u64 {
+        connections
+            .iter()
+            .filter_map(|((x, y), nums)| {
+                if nums.len() == 2 && self.symbols.get(&(*x, *y)) == Some(&'*') {
+                    Some(nums.iter().product::<u64>())
+                } else {
+                    None
+                }
+            })
+            .sum()
+    }
+}
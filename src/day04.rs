@@ -0,0 +1,116 @@
+use std::{collections::HashSet, str::FromStr};
+
+use anyhow::Result;
+
+use crate::{
+    error::{parse_lines, ParseError},
+    util::input,
+    Solution,
+};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Card>;
+    type Part1 = u64;
+    type Part2 = usize;
+
+    fn parse(raw: &str) -> Result<Self::Input> {
+        Ok(parse_lines(raw.trim())?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<u64> {
+        Ok(input.iter().map(Card::points).sum())
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        Ok(total_cards(input))
+    }
+}
+
+#[derive(Debug)]
+pub struct Card {
+    winning_numbers: HashSet<u32>,
+    numbers_you_have: HashSet<u32>,
+}
+
+impl FromStr for Card {
+    type Err = ParseError;
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        let (_, numbers) = input::key_value(raw)?;
+        let (winning, yours) =
+            input::split_pair(numbers, "|").map_err(|e| e.rebase(raw, numbers))?;
+        let winning_numbers = parse_numbers(raw, winning)?;
+        let numbers_you_have = parse_numbers(raw, yours)?;
+        Ok(Self {
+            winning_numbers,
+            numbers_you_have,
+        })
+    }
+}
+
+fn parse_numbers(line: &str, raw: &str) -> Result<HashSet<u32>, ParseError> {
+    let numbers = input::numbers(raw).map_err(|e| e.rebase(line, raw))?;
+    Ok(numbers.into_iter().collect())
+}
+
+impl Card {
+    fn points(&self) -> u64 {
+        let wins = self.wins() as u32;
+        if wins == 0 {
+            0
+        } else {
+            2_u64.pow(wins - 1)
+        }
+    }
+
+    fn wins(&self) -> usize {
+        self.numbers_you_have
+            .intersection(&self.winning_numbers)
+            .count()
+    }
+}
+
+fn total_cards(cards: &[Card]) -> usize {
+    let mut adders = vec![0i32; cards.len()];
+    let mut running_total = 0;
+    let mut current_cards = 1;
+    for (i, card) in cards.iter().enumerate() {
+        current_cards += adders[i];
+        running_total += current_cards;
+        let wins = card.wins();
+        if wins > 0 {
+            if i + 1 < cards.len() {
+                adders[i + 1] += current_cards;
+            }
+            let reset_pos = i + wins + 1;
+            if reset_pos < cards.len() {
+                adders[reset_pos] -= current_cards;
+            }
+        }
+    }
+    running_total as usize
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example() {
+        let input = r#"
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+"#;
+        let cards = parse_lines::<Card>(input.trim()).expect("error parsing cards");
+
+        assert_eq!(30, total_cards(&cards));
+    }
+}
@@ -0,0 +1,134 @@
+use anyhow::Result;
+
+use crate::{
+    error::{ParseError, SolveError},
+    util::{
+        input,
+        interval::{Interval, IntervalSet, LinearMap},
+    },
+    Solution,
+};
+use std::collections::HashMap;
+
+pub type MaterialMaps = HashMap<String, (String, LinearMap)>;
+pub type Seeds = Vec<i64>;
+
+const SEED: &str = "seed";
+const LOCATION: &str = "location";
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = (Seeds, LinearMap);
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(raw: &str) -> Result<Self::Input> {
+        let (seeds, material_maps) = parse(raw)?;
+        let seed_to_location = compress_material_maps(&material_maps)?;
+        Ok((seeds, seed_to_location))
+    }
+
+    fn part1((seeds, seed_to_location): &Self::Input) -> Result<i64> {
+        seeds
+            .iter()
+            .map(|seed| seed_to_location.apply(*seed))
+            .min()
+            .ok_or_else(|| SolveError::NoSolution("there are no seeds".to_string()).into())
+    }
+
+    fn part2((seeds, seed_to_location): &Self::Input) -> Result<i64> {
+        let ranges = seeds.chunks_exact(2);
+        if !ranges.remainder().is_empty() {
+            return Err(SolveError::Invariant(
+                "seeds should pair up into starts and lengths".to_string(),
+            )
+            .into());
+        }
+        let seeds: IntervalSet = ranges
+            .map(|pair| Interval::with_len(pair[0], pair[1]))
+            .collect();
+        seed_to_location
+            .apply_set(&seeds)
+            .min()
+            .ok_or_else(|| SolveError::NoSolution("there are no seeds".to_string()).into())
+    }
+}
+
+fn parse(raw: &str) -> Result<(Seeds, MaterialMaps), ParseError> {
+    let mut sections = input::sections(raw);
+    let seeds = sections
+        .next()
+        .ok_or_else(|| ParseError::missing(raw, "a seed section"))?;
+    let seeds = parse_seeds(seeds).map_err(|e| e.rebase(raw, seeds))?;
+    let material_maps = sections
+        .map(|section| parse_material_map(section).map_err(|e| e.rebase(raw, section)))
+        .collect::<Result<MaterialMaps, _>>()?;
+    Ok((seeds, material_maps))
+}
+
+fn parse_seeds(raw: &str) -> Result<Seeds, ParseError> {
+    let seeds = input::labelled(raw, "seeds:")?;
+    input::numbers(seeds).map_err(|e| e.rebase(raw, seeds))
+}
+
+fn parse_material_map(raw: &str) -> Result<(String, (String, LinearMap)), ParseError> {
+    let mut lines = input::lines(raw);
+    let title = lines.next().unwrap_or_default();
+    let (from, to) = title
+        .split_whitespace()
+        .next()
+        .and_then(|t| t.split_once("-to-"))
+        .ok_or_else(|| ParseError::new(raw, title, "a `<from>-to-<to> map:` title"))?;
+
+    let mut map = LinearMap::new();
+    for line in lines {
+        let (domain, offset) = parse_range(line).map_err(|e| e.rebase(raw, line))?;
+        map.insert(domain, offset);
+    }
+    Ok((from.to_string(), (to.to_string(), map)))
+}
+
+fn parse_range(raw: &str) -> Result<(Interval, i64), ParseError> {
+    let mut nums = raw
+        .split_whitespace()
+        .map(|s| s.parse().map_err(|_| ParseError::new(raw, s, "a number")));
+    let start_to: i64 = nums
+        .next()
+        .ok_or_else(|| ParseError::missing(raw, "a destination start"))??;
+    let start_from = nums
+        .next()
+        .ok_or_else(|| ParseError::missing(raw, "a source start"))??;
+    let size = nums
+        .next()
+        .ok_or_else(|| ParseError::missing(raw, "a range size"))??;
+    if size < 1 {
+        let size = raw.split_whitespace().nth(2).unwrap_or_default();
+        return Err(ParseError::new(raw, size, "a range size of at least 1"));
+    }
+    Ok((Interval::with_len(start_from, size), start_to - start_from))
+}
+
+/// Chains the maps from seed through to location into one.
+fn compress_material_maps(material_maps: &MaterialMaps) -> Result<LinearMap, SolveError> {
+    let mut seed_to_location = LinearMap::new();
+    let mut source = SEED;
+    let mut used = 0;
+    while source != LOCATION {
+        // using more maps than there are means the chain has gone round in a circle
+        if used == material_maps.len() {
+            return Err(SolveError::Invariant(
+                "the maps from seed never reach location".to_string(),
+            ));
+        }
+        let (destination, map) = material_maps
+            .get(source)
+            .ok_or_else(|| SolveError::Invariant(format!("there is no map from {source}")))?;
+        seed_to_location = seed_to_location.then(map);
+        source = destination;
+        used += 1;
+    }
+    Ok(seed_to_location)
+}
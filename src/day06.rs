@@ -0,0 +1,69 @@
+use anyhow::Result;
+
+use crate::{error::ParseError, util::input, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Races;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(raw: &str) -> Result<Self::Input> {
+        let raw = raw.trim();
+        let mut lines = input::lines(raw);
+        let times = labelled(raw, lines.next(), "Time:")?;
+        let distances = labelled(raw, lines.next(), "Distance:")?;
+        Ok(Races {
+            races: parse_numbers(raw, times)?
+                .into_iter()
+                .zip(parse_numbers(raw, distances)?)
+                .collect(),
+            big_race: (parse_kerned(raw, times)?, parse_kerned(raw, distances)?),
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        Ok(input
+            .races
+            .iter()
+            .map(|(t, d)| ways_to_win(*t, *d))
+            .product())
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        let (time, distance) = input.big_race;
+        Ok(ways_to_win(time, distance))
+    }
+}
+
+pub struct Races {
+    races: Vec<(u64, u64)>,
+    big_race: (u64, u64),
+}
+
+fn labelled<'a>(raw: &'a str, line: Option<&'a str>, label: &str) -> Result<&'a str, ParseError> {
+    let line = line.ok_or_else(|| ParseError::missing(raw, format!("a `{label}` line")))?;
+    input::labelled(line, label).map_err(|e| e.rebase(raw, line))
+}
+
+fn parse_numbers(raw: &str, line: &str) -> Result<Vec<u64>, ParseError> {
+    input::numbers(line).map_err(|e| e.rebase(raw, line))
+}
+
+// part 2 reads each line as a single number, ignoring the spaces between digits.
+fn parse_kerned(raw: &str, line: &str) -> Result<u64, ParseError> {
+    line.split_whitespace()
+        .collect::<String>()
+        .parse()
+        .map_err(|_| ParseError::new(raw, line.trim(), "digits"))
+}
+
+fn ways_to_win(time: u64, distance: u64) -> usize {
+    // meh, just brute force it man
+    (0..time)
+        .filter(|charge_time| (time - charge_time) * charge_time > distance)
+        .count()
+}
@@ -0,0 +1,156 @@
+use std::{cmp::Ordering, collections::HashMap, str::FromStr};
+
+use anyhow::{Context, Result};
+
+use crate::{
+    error::{parse_lines, ParseError},
+    Solution,
+};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<Bid>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(raw: &str) -> Result<Self::Input> {
+        parse_lines(raw.trim()).context("failed to make bids")
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        Ok(get_score(&mut input.clone()))
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        let mut bids = input.clone();
+        into_pt2(&mut bids);
+        Ok(get_score(&mut bids))
+    }
+}
+
+type Label = u8;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Hand(Vec<Label>);
+
+impl Hand {
+    fn valued(&self) -> (Vec<u8>, Vec<Label>) {
+        let mut counts: HashMap<Label, u8> = HashMap::new();
+        for label in &self.0 {
+            (*counts.entry(*label).or_default()) += 1;
+        }
+        let joker = counts.remove(&1).unwrap_or_default();
+        let mut values = counts.into_values().collect::<Vec<_>>();
+        values.sort_by(|a, b| b.cmp(a));
+        if !values.is_empty() {
+            values[0] += joker;
+        } else {
+            values.push(joker)
+        }
+        (values, self.0.clone())
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.valued().cmp(&other.valued())
+    }
+}
+
+impl FromStr for Hand {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let labels = s
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                Ok(match c {
+                    'A' => 14,
+                    'K' => 13,
+                    'Q' => 12,
+                    'J' => 11,
+                    'T' => 10,
+                    n => n
+                        .to_digit(10)
+                        .ok_or_else(|| ParseError::at(s, i, "a card (AKQJT or 2-9)"))?
+                        as u8,
+                })
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+        Ok(Hand(labels))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+pub struct Bid {
+    hand: Hand,
+    bid: u32,
+}
+
+impl FromStr for Bid {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (hand, bid) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::missing(s, "a hand and a bid"))?;
+        let hand = Hand::from_str(hand).map_err(|e| e.rebase(s, hand))?;
+        let bid = bid
+            .parse::<u32>()
+            .map_err(|_| ParseError::new(s, bid, "a bid"))?;
+        Ok(Self { hand, bid })
+    }
+}
+
+fn get_score(bids: &mut [Bid]) -> usize {
+    bids.sort();
+    bids.iter()
+        .enumerate()
+        .map(|(rank, bid)| (rank + 1) * bid.bid as usize)
+        .sum::<usize>()
+}
+
+fn into_pt2(bids: &mut [Bid]) {
+    for bid in bids.iter_mut() {
+        for label in bid.hand.0.iter_mut() {
+            if *label == 11 {
+                *label = 1
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn example() {
+        let contents = r#"
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+"#;
+        let mut bids = parse_lines::<Bid>(contents.trim()).expect("failed to make bids");
+        bids.sort();
+        let score = bids
+            .iter()
+            .enumerate()
+            .map(|(rank, bid)| (rank + 1) * bid.bid as usize)
+            .sum::<usize>();
+        assert_eq!(score, 6440);
+    }
+}
@@ -0,0 +1,224 @@
+use std::collections::BTreeMap;
+
+use anyhow::{Context, Result};
+
+use crate::{
+    error::{ParseError, SolveError},
+    util::{
+        cycle::{self, Cycle},
+        direction::Turn,
+        input,
+        math::Congruence,
+    },
+    Solution,
+};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Navigator;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(raw: &str) -> Result<Self::Input> {
+        parse(raw.trim())
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        Ok(input.navigate()?)
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        Ok(input.get_all_steps()?)
+    }
+}
+
+type Directions = Vec<Turn>;
+type Node = String;
+
+#[derive(Debug)]
+struct Paths {
+    left: Node,
+    right: Node,
+}
+type Network = BTreeMap<Node, Paths>;
+
+pub struct Navigator {
+    network: Network,
+    directions: Directions,
+}
+
+impl Navigator {
+    fn navigate(&self) -> Result<usize, SolveError> {
+        if !self.network.contains_key("AAA") {
+            return Err(SolveError::NoSolution("there is no AAA node".to_string()));
+        }
+        self.get_steps("AAA")
+    }
+
+    fn get_steps(&self, starting_point: &str) -> Result<usize, SolveError> {
+        // Once the walk is going round in circles every node it will ever visit is in the
+        // cycle's states.
+        self.walk(starting_point)?
+            .states()
+            .iter()
+            .position(|(node, _)| node.ends_with('Z'))
+            .ok_or_else(|| {
+                SolveError::NoSolution(format!("{starting_point} never reaches a node ending in Z"))
+            })
+    }
+
+    /// The nodes visited from `starting_point`, each paired with the index of the direction
+    /// to take next.
+    fn walk<'a>(&'a self, starting_point: &'a str) -> Result<Cycle<(&'a str, usize)>, SolveError> {
+        cycle::try_find((starting_point, 0), |&(current, direction_idx)| {
+            let node = self
+                .network
+                .get(current)
+                .ok_or_else(|| SolveError::Invariant(format!("{current} is not in the network")))?;
+            let next = match self.directions[direction_idx] {
+                Turn::Left => &node.left,
+                Turn::Right => &node.right,
+                Turn::Back => {
+                    return Err(SolveError::Invariant(
+                        "directions should only turn left or right".to_string(),
+                    ))
+                }
+            };
+            Ok((next.as_str(), (direction_idx + 1) % self.directions.len()))
+        })
+    }
+
+    fn get_all_steps(&self) -> Result<usize, SolveError> {
+        let walks = self
+            .network
+            .keys()
+            .filter(|key| key.ends_with('A'))
+            .map(|key| self.walk(key))
+            .collect::<Result<Vec<_>, _>>()?;
+        if walks.is_empty() {
+            return Err(SolveError::NoSolution(
+                "there are no nodes ending in A".to_string(),
+            ));
+        }
+        let at_z = |walk: &Cycle<(&str, usize)>, n: usize| walk.state_at(n).0.ends_with('Z');
+
+        // Until every walk has reached its loop, just check each step.
+        let settled = walks.iter().map(|walk| walk.prefix).max().unwrap_or(0);
+        if let Some(steps) = (0..settled).find(|&n| walks.iter().all(|walk| at_z(walk, n))) {
+            return Ok(steps);
+        }
+        // From then on each walk is at a Z node on some fixed residues of its period, and
+        // the ghosts line up wherever one residue from each agrees.
+        let mut candidates = vec![Congruence::ALL];
+        for walk in &walks {
+            let residues = (walk.prefix..walk.prefix + walk.period)
+                .filter(|&n| at_z(walk, n))
+                .map(|n| {
+                    Congruence::new(n as i64, walk.period as i64).ok_or_else(|| {
+                        SolveError::Invariant(
+                            "a walk's loop should be at least one step".to_string(),
+                        )
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            candidates = candidates
+                .iter()
+                .flat_map(|c| residues.iter().filter_map(|r| c.merge(r)))
+                .collect();
+        }
+        candidates
+            .iter()
+            .filter_map(|c| c.first_at_least(settled as i64))
+            .min()
+            .map(|steps| steps as usize)
+            .ok_or_else(|| {
+                SolveError::NoSolution(
+                    "the ghosts are never all on Z nodes at once within i64 steps".to_string(),
+                )
+            })
+    }
+}
+
+fn parse(content: &str) -> Result<Navigator> {
+    let mut parts = input::sections(content);
+    let line = parts
+        .next()
+        .ok_or_else(|| ParseError::missing(content, "a line of L and R directions"))?;
+    let directions = line
+        .chars()
+        .enumerate()
+        .map(|(i, c)| match Turn::try_from(c) {
+            Ok(turn @ (Turn::Left | Turn::Right)) => Ok(turn),
+            _ => Err(ParseError::at(line, i, "L or R").rebase(content, line)),
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .context("failed to parse direction")?;
+    let network = parts
+        .next()
+        .ok_or_else(|| ParseError::missing(content, "a blank line then the network"))?;
+    let network = input::lines(network)
+        .map(|line| parse_node(line).map_err(|e| e.rebase(content, line)))
+        .collect::<Result<Network, _>>()?;
+    Ok(Navigator {
+        directions,
+        network,
+    })
+}
+
+fn parse_node(line: &str) -> Result<(Node, Paths), ParseError> {
+    match line.chars().collect::<Vec<_>>()[..] {
+        [n, w, k, ' ', '=', ' ', '(', l, f, t, ',', ' ', r, g, h, ')'] => Ok((
+            format!("{n}{w}{k}"),
+            Paths {
+                left: format!("{l}{f}{t}"),
+                right: format!("{r}{g}{h}"),
+            },
+        )),
+        _ => Err(ParseError::new(
+            line,
+            line,
+            "a node like `AAA = (BBB, CCC)`",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn ghosts_line_up_after_their_first_z() {
+        // 11A is on a Z node at every even step from 2 and 22A at steps 1, 4, 7, ..., so
+        // the lcm of their first Z steps (2) is wrong
+        let input = "L
+
+11A = (11B, 11B)
+11B = (11Z, 11Z)
+11Z = (11B, 11B)
+22A = (22Z, 22Z)
+22Z = (22B, 22B)
+22B = (22C, 22C)
+22C = (22Z, 22Z)";
+        let navigator = Day08::parse(input).unwrap();
+        assert_eq!(4, Day08::part2(&navigator).unwrap());
+
+        // 33A is only on a Z node at odd steps, which never agrees with 11A
+        let input = "L
+
+11A = (11B, 11B)
+11B = (11Z, 11Z)
+11Z = (11B, 11B)
+33A = (33Z, 33Z)
+33Z = (33B, 33B)
+33B = (33Z, 33Z)";
+        let navigator = Day08::parse(input).unwrap();
+        let err = Day08::part2(&navigator).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref(),
+            Some(SolveError::NoSolution(_))
+        ));
+    }
+}
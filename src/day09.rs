@@ -0,0 +1,61 @@
+use anyhow::Result;
+
+use crate::{error::ParseError, util::input, Solution};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Vec<i32>>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(raw: &str) -> Result<Self::Input> {
+        Ok(input::parse_lines_with(raw.trim(), |line| {
+            let sequence = input::numbers(line)?;
+            if sequence.is_empty() {
+                return Err(ParseError::missing(line, "a sequence of numbers"));
+            }
+            Ok(sequence)
+        })?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<i32> {
+        Ok(input.iter().map(|seq| op_value(seq, next)).sum())
+    }
+
+    fn part2(input: &Self::Input) -> Result<i32> {
+        Ok(input.iter().map(|seq| op_value(seq, prev)).sum())
+    }
+}
+
+fn next(sequence: &[i32], diff: i32) -> i32 {
+    sequence[sequence.len() - 1] + diff
+}
+
+fn prev(sequence: &[i32], diff: i32) -> i32 {
+    sequence[0] - diff
+}
+
+fn op_value(sequence: &[i32], op: fn(&[i32], i32) -> i32) -> i32 {
+    let history: Vec<i32> = sequence.windows(2).map(|w| w[1] - w[0]).collect();
+    let diff = if history.iter().all(|d| *d == 0) {
+        0
+    } else {
+        op_value(&history, op)
+    };
+    op(sequence, diff)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rejects_blank_lines() {
+        let err = Day09::parse("1 2 3\n\n4 5 6").unwrap_err();
+        let err = err.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((1, 0), (err.line, err.column));
+    }
+}
@@ -0,0 +1,120 @@
+use std::str::FromStr;
+
+use anyhow::{bail, Error, Result};
+
+use crate::{
+    error::{ParseError, SolveError},
+    util::{bidimap::Parsed, direction::Direction, geometry, grid::Grid},
+    Solution,
+};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Diagram;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(raw: &str) -> Result<Self::Input> {
+        Ok(Diagram::from_str(raw.trim())?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        let loop_path = input.find_loop()?;
+        Ok(loop_path.len() / 2)
+    }
+
+    fn part2(input: &Self::Input) -> Result<u64> {
+        let loop_path = input.find_loop()?;
+        let vertices: Vec<_> = loop_path
+            .into_iter()
+            .map(|(x, y)| (x as i64, y as i64))
+            .collect();
+        // the loop runs through the middle of its tiles, so the tiles it encloses are the
+        // lattice points inside it
+        Ok(geometry::interior_points(&vertices))
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Default)]
+enum Point {
+    Pipe(Direction, Direction),
+    #[default]
+    Ground,
+    Start,
+}
+
+impl TryFrom<char> for Parsed<Point> {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self> {
+        Ok(Parsed::Item(match value {
+            '|' => Point::Pipe(Direction::N, Direction::S),
+            '-' => Point::Pipe(Direction::E, Direction::W),
+            'L' => Point::Pipe(Direction::N, Direction::E),
+            'J' => Point::Pipe(Direction::N, Direction::W),
+            '7' => Point::Pipe(Direction::S, Direction::W),
+            'F' => Point::Pipe(Direction::S, Direction::E),
+            '.' => Point::Ground,
+            'S' => Point::Start,
+            c => bail!("unrecognised character {c}"),
+        }))
+    }
+}
+
+pub struct Diagram {
+    points: Grid<Point>,
+    start: (usize, usize),
+}
+
+impl FromStr for Diagram {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let points = Grid::<Point>::from_str(s)?;
+        let start = points
+            .iter()
+            .find(|(_, point)| **point == Point::Start)
+            .map(|(pos, _)| pos)
+            .ok_or_else(|| ParseError::missing(s, "a start (S)"))?;
+        Ok(Self { points, start })
+    }
+}
+
+impl Diagram {
+    fn step(
+        &self,
+        (cur_x, cur_y): (usize, usize),
+        direction: Direction,
+    ) -> Option<((usize, usize), Direction)> {
+        let next = self.points.neighbour((cur_x, cur_y), direction)?;
+        match self.points[next] {
+            Point::Start => Some((next, direction)),
+            Point::Pipe(d1, d2) if d1.is_opposite(direction) => Some((next, d2)),
+            Point::Pipe(d1, d2) if d2.is_opposite(direction) => Some((next, d1)),
+            _ => None,
+        }
+    }
+
+    /// The tiles of the loop through the start, in the order they are walked.
+    fn find_loop(&self) -> Result<Vec<(usize, usize)>, SolveError> {
+        for direction in Direction::ALL {
+            let mut loop_path = vec![];
+            let mut current_pos = self.start;
+            let mut current_dir = direction;
+            while let Some((pos, dir)) = self.step(current_pos, current_dir) {
+                current_pos = pos;
+                current_dir = dir;
+                loop_path.push(current_pos);
+                if current_pos == self.start {
+                    return Ok(loop_path);
+                }
+            }
+        }
+        Err(SolveError::NoSolution(
+            "no loop runs through the start".to_string(),
+        ))
+    }
+}
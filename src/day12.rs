@@ -0,0 +1,202 @@
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+
+use crate::{
+    error::{parse_lines, ParseError},
+    util::memo::Memo,
+    Solution,
+};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Vec<Entry>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(raw: &str) -> Result<Self::Input> {
+        parse_lines(raw.trim()).context("failed to parse")
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        Ok(input.iter().map(Entry::arrangements).sum())
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        Ok(input.iter().map(|e| e.unfold().arrangements()).sum())
+    }
+}
+
+type Record = Vec<Condition>;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+enum Condition {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+impl TryFrom<char> for Condition {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '?' => Ok(Self::Unknown),
+            '.' => Ok(Self::Operational),
+            '#' => Ok(Self::Damaged),
+            c => Err(c),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Entry {
+    record: Record,
+    groups: Vec<usize>,
+}
+
+impl FromStr for Entry {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (record, groups) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::missing(s, "a record and its groups"))?;
+        let record = record
+            .chars()
+            .enumerate()
+            .map(|(i, c)| {
+                Condition::try_from(c).map_err(|_| ParseError::at(s, i, "a condition (., # or ?)"))
+            })
+            .collect::<Result<_, _>>()?;
+        let groups = groups
+            .split(',')
+            .map(|g| match g.parse::<usize>() {
+                Ok(size) if size > 0 => Ok(size),
+                _ => Err(ParseError::new(s, g, "a group size of at least 1")),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self { record, groups })
+    }
+}
+
+impl Entry {
+    fn arrangements(&self) -> usize {
+        try_arrangements(&mut Memo::new(), &self.record, &self.groups)
+    }
+
+    // part 2 repeats the record 5 times separated by unknowns, and the groups to match.
+    fn unfold(&self) -> Self {
+        let mut record = self.record.clone();
+        for _ in 1..5 {
+            record.push(Condition::Unknown);
+            record.extend(&self.record);
+        }
+        Self {
+            record,
+            groups: self.groups.repeat(5),
+        }
+    }
+}
+
+// Every call works on the ends of one entry's record and groups, so their lengths are
+// enough to tell calls apart.
+type Arrangements = Memo<(usize, usize), usize>;
+
+fn try_arrangements(memo: &mut Arrangements, record: &[Condition], groups: &[usize]) -> usize {
+    memo.get_or_compute((record.len(), groups.len()), |memo| {
+        match (record, groups) {
+            // If we don't have any more groups, then this arrangement works
+            // if there are no remaining damaged conditions in the record.
+            (r, []) => {
+                if has_damaged(r) {
+                    0
+                } else {
+                    1
+                }
+            }
+            ([], [_, ..]) => 0,
+            ([Condition::Operational, rest @ ..], g) => try_arrangements(memo, rest, g),
+            ([Condition::Damaged, rest @ ..], g) => damaged_arrangements(memo, rest, g),
+            ([Condition::Unknown, rest @ ..], g) => {
+                try_arrangements(memo, rest, g) + damaged_arrangements(memo, rest, g)
+            }
+        }
+    })
+}
+
+// The arrangements when the condition before `rest` is damaged, starting the first group.
+fn damaged_arrangements(memo: &mut Arrangements, rest: &[Condition], groups: &[usize]) -> usize {
+    let [g, grest @ ..] = groups else {
+        return 0;
+    };
+    if rest.len() < g - 1 || has_operational(&rest[..(g - 1)]) {
+        0
+    } else {
+        let mut next_slice = &rest[(g - 1)..];
+        if !next_slice.is_empty() {
+            if next_slice[0] == Condition::Damaged {
+                return 0;
+            } else {
+                next_slice = &next_slice[1..]
+            }
+        }
+        try_arrangements(memo, next_slice, grest)
+    }
+}
+
+fn has_damaged(record: &[Condition]) -> bool {
+    record.contains(&Condition::Damaged)
+}
+
+fn has_operational(record: &[Condition]) -> bool {
+    record.contains(&Condition::Operational)
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn single_rows() {
+        let entry = Entry::from_str("???.### 1,1,3").unwrap();
+        assert_eq!(1, entry.arrangements());
+        let entry = Entry::from_str("?###???????? 3,2,1").unwrap();
+        assert_eq!(10, entry.arrangements());
+        let entry = Entry::from_str("????.######..#####. 1,6,5").unwrap();
+        assert_eq!(4, entry.arrangements());
+    }
+
+    #[test]
+    fn memo_stays_small() {
+        let entry = Entry::from_str("?###???????? 3,2,1").unwrap();
+        let mut memo = Memo::new();
+        assert_eq!(
+            10,
+            try_arrangements(&mut memo, &entry.record, &entry.groups)
+        );
+        let stats = memo.stats();
+        assert!(stats.entries <= (entry.record.len() + 1) * (entry.groups.len() + 1));
+        assert!(stats.hits > 0);
+    }
+
+    #[test]
+    fn example() {
+        let input = r#"
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+"#
+        .trim();
+        let entries = Day12::parse(input).unwrap();
+        assert_eq!(21, Day12::part1(&entries).unwrap());
+        assert_eq!(525152, Day12::part2(&entries).unwrap());
+    }
+}
@@ -0,0 +1,125 @@
+use std::{cmp::min, collections::BTreeSet, str::FromStr};
+
+use anyhow::{Context, Result};
+
+use crate::{
+    error::{ParseError, SolveError},
+    util::{grid::Grid, input, transform::Transform},
+    Solution,
+};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<Pattern>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(raw: &str) -> Result<Self::Input> {
+        input::parse_sections(raw).context("unparseable pattern")
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        Ok(input.iter().map(Pattern::mirror).sum())
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        Ok(input
+            .clone()
+            .iter_mut()
+            .map(Pattern::smudged_mirror)
+            .sum::<Result<_, _>>()?)
+    }
+}
+
+#[derive(Clone)]
+pub struct Pattern {
+    grid: Grid<char>,
+}
+
+impl FromStr for Pattern {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let width = input::lines(s).next().unwrap_or_default().chars().count();
+        for line in input::lines(s) {
+            if let Some(y) = line.chars().position(|c| c != '.' && c != '#') {
+                return Err(ParseError::at(line, y, "ash (.) or rock (#)").rebase(s, line));
+            }
+            if line.chars().count() != width {
+                return Err(ParseError::new(s, line, format!("a row {width} wide")));
+            }
+        }
+        let rows = input::lines(s).map(|s| s.chars().collect()).collect();
+        let grid = Grid::from_rows(rows)
+            .ok_or_else(|| ParseError::new(s, s, format!("rows {width} wide")))?;
+        Ok(Self { grid })
+    }
+}
+
+impl Pattern {
+    fn mirror(&self) -> usize {
+        let row_mirror = find_mirror(self.grid.rows(), None);
+        let column_mirror = find_mirror(self.transposed().rows(), None);
+        row_mirror + 100 * column_mirror
+    }
+
+    fn transposed(&self) -> Grid<char> {
+        self.grid.transformed(Transform::Transpose)
+    }
+
+    fn smudged_mirror(&mut self) -> Result<usize, SolveError> {
+        let mirror = self.mirror();
+        let exclude_x = Some(mirror % 100);
+        let exclude_y = Some(mirror / 100);
+        let (w, h) = self.grid.dimensions();
+        for y in 0..h {
+            for x in 0..w {
+                let old = self.grid[(x, y)];
+                self.grid[(x, y)] = if old == '.' { '#' } else { '.' };
+                let row_mirror = find_mirror(self.grid.rows(), exclude_x);
+                let column_mirror = find_mirror(self.transposed().rows(), exclude_y);
+                self.grid[(x, y)] = old;
+                if row_mirror > 0 {
+                    return Ok(row_mirror);
+                }
+                if column_mirror > 0 {
+                    return Ok(column_mirror * 100);
+                }
+            }
+        }
+        Err(SolveError::NoSolution(
+            "no single smudge gives a new mirror".to_string(),
+        ))
+    }
+}
+
+fn find_mirror<L: AsRef<[char]>>(lines: impl Iterator<Item = L>, exclude: Option<usize>) -> usize {
+    let mut candidates = lines
+        .map(|line| mirror_points(line.as_ref()))
+        .reduce(|acc, e| acc.intersection(&e).cloned().collect())
+        .unwrap_or_default();
+    if let Some(exclude) = exclude {
+        candidates.remove(&exclude);
+    }
+    candidates.pop_first().unwrap_or(0)
+}
+
+fn mirror_points(line: &[char]) -> BTreeSet<usize> {
+    (1..line.len())
+        .filter(|i| mirrors_at_point(*i, line))
+        .collect()
+}
+
+fn mirrors_at_point(idx: usize, line: &[char]) -> bool {
+    let length = min(idx, line.len() - idx);
+    let start = idx - length;
+    let end = idx + length;
+    is_palindrome(&line[start..end])
+}
+
+fn is_palindrome(slice: &[char]) -> bool {
+    slice.iter().zip(slice.iter().rev()).all(|(a, b)| a == b)
+}
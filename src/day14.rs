@@ -0,0 +1,182 @@
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+
+use crate::{
+    error::ParseError,
+    util::{bidimap::BidiMap, cycle, direction::Direction, input, transform::Transform},
+    Solution,
+};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Platform;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(raw: &str) -> Result<Self::Input> {
+        Platform::from_str(raw.trim()).context("failed to parse platform")
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        let mut platform = input.clone();
+        platform.tilt(Direction::N);
+        Ok(platform.total_load())
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        let mut platform = input.clone();
+        platform.spin(1_000_000_000);
+        Ok(platform.total_load())
+    }
+}
+
+#[derive(Clone)]
+pub struct Platform {
+    round_rocks: BidiMap<()>,
+    cube_rocks: BidiMap<()>,
+}
+
+impl FromStr for Platform {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let h = input::lines(s).count();
+        let w = input::lines(s)
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or_default();
+        let mut round_rocks = BidiMap::with_dimensions(w, h);
+        let mut cube_rocks = BidiMap::with_dimensions(w, h);
+        for (y, line) in input::lines(s).enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                match c {
+                    '#' => {
+                        cube_rocks.insert(x, y, ());
+                    }
+                    'O' => {
+                        round_rocks.insert(x, y, ());
+                    }
+                    '.' => {}
+                    _ => {
+                        return Err(
+                            ParseError::at(line, x, "a rock (O or #) or empty space (.)")
+                                .rebase(s, line),
+                        )
+                    }
+                }
+            }
+        }
+        Ok(Self {
+            round_rocks,
+            cube_rocks,
+        })
+    }
+}
+
+impl Platform {
+    fn tilt(&mut self, direction: Direction) {
+        self.round_rocks = self.tilted(&self.round_rocks, direction);
+    }
+
+    /// Where `round_rocks` end up after rolling as far as they can in `direction`.
+    fn tilted(&self, round_rocks: &BidiMap<()>, direction: Direction) -> BidiMap<()> {
+        // turn the platform so that rocks always roll north, then turn it back
+        let turn = Transform::facing_north(direction);
+        let cubes = self.cube_rocks.view(turn);
+        let rounds = round_rocks.view(turn);
+        let (w, h) = rounds.dimensions();
+        let mut tilted = BidiMap::with_dimensions(w, h);
+        for x in 0..w {
+            let mut cubes = cubes.column(x).map(|((_, y), _)| y).peekable();
+            let mut free = 0;
+            for ((_, y), _) in rounds.column(x) {
+                // a rock stops below the last cube rock above it or the rock before it
+                while let Some(cube) = cubes.next_if(|cube| *cube < y) {
+                    free = free.max(cube + 1);
+                }
+                tilted.insert(x, free, ());
+                free += 1;
+            }
+        }
+        tilted.transformed(turn.inverse())
+    }
+
+    fn total_load(&self) -> usize {
+        let (_, h) = self.round_rocks.dimensions();
+        self.round_rocks.iter_by_x().map(|((_, y), _)| h - y).sum()
+    }
+
+    fn spin(&mut self, reps: usize) {
+        let spin_directions = [Direction::N, Direction::W, Direction::S, Direction::E];
+        let cycle = cycle::find(self.round_rocks.clone(), |rocks| {
+            spin_directions
+                .iter()
+                .fold(rocks.clone(), |rocks, direction| {
+                    self.tilted(&rocks, *direction)
+                })
+        });
+        self.round_rocks = cycle.state_at(reps).clone();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::render::Render;
+
+    fn render(platform: &Platform) -> Render<'_> {
+        Render::new(platform.round_rocks.dimensions())
+            .map(&platform.cube_rocks, |_| '#')
+            .map(&platform.round_rocks, |_| 'O')
+    }
+
+    #[test]
+    fn example() {
+        let example = r#"
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+"#
+        .trim();
+        let mut platform = Platform::from_str(example).expect("should be parsed");
+        platform.tilt(Direction::N);
+        let tilted = r#"
+OOOO.#.O..
+OO..#....#
+OO..O##..O
+O..#.OO...
+........#.
+..#....#.#
+..O..#.O.O
+..O.......
+#....###..
+#....#....
+"#
+        .trim();
+        assert_eq!(tilted, render(&platform).to_string());
+        assert_eq!(136, platform.total_load());
+        platform.spin(1_000_000_000);
+        assert_eq!(64, platform.total_load());
+    }
+
+    #[test]
+    fn single_row() {
+        let platform = Day14::parse("O.O#").unwrap();
+        assert_eq!(2, Day14::part1(&platform).unwrap());
+        assert_eq!(2, Day14::part2(&platform).unwrap());
+        let mut platform = Platform::from_str("O.O#").unwrap();
+        platform.tilt(Direction::E);
+        assert_eq!(".OO#", render(&platform).to_string());
+    }
+}
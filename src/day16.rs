@@ -0,0 +1,155 @@
+use std::{collections::HashSet, str::FromStr};
+
+use anyhow::{bail, Context, Error, Result};
+
+use crate::{
+    util::{
+        bidimap::{BidiMap, Parsed},
+        direction::Direction,
+        search::{self, Walker},
+    },
+    Solution,
+};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Grid;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(raw: &str) -> Result<Self::Input> {
+        Ok(Grid::from_str(raw)?)
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize> {
+        let energized = process_beams(input, Beam::new((0, 0), Direction::E));
+        Ok(energized.len())
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize> {
+        let (w, h) = input.dimensions();
+        (0..w)
+            .map(|x| {
+                [
+                    Beam::new((x, 0), Direction::S),
+                    Beam::new((x, h - 1), Direction::N),
+                ]
+            })
+            .chain((0..h).map(|y| {
+                [
+                    Beam::new((0, y), Direction::E),
+                    Beam::new((w - 1, y), Direction::W),
+                ]
+            }))
+            .flatten()
+            .map(|initial| process_beams(input, initial).len())
+            .max()
+            .context("should have edge beams")
+    }
+}
+
+pub enum Mirror {
+    Forward,  // \
+    Backward, // /
+    Horizontal,
+    Vertical,
+}
+
+impl TryFrom<char> for Parsed<Mirror> {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        let item = match value {
+            '\\' => Parsed::Item(Mirror::Forward),
+            '/' => Parsed::Item(Mirror::Backward),
+            '-' => Parsed::Item(Mirror::Horizontal),
+            '|' => Parsed::Item(Mirror::Vertical),
+            '.' => Parsed::Skip,
+            _ => bail!("unknown char"),
+        };
+        Ok(item)
+    }
+}
+
+type Beam = Walker;
+
+pub type Grid = BidiMap<Mirror>;
+type Energized = HashSet<(usize, usize)>;
+
+fn process_beams(grid: &Grid, initial: Beam) -> Energized {
+    search::reachable([initial], |beam| next_beams(grid, *beam))
+        .into_iter()
+        .map(|beam| beam.pos)
+        .collect()
+}
+
+/// Where a beam goes after passing through its current tile.
+fn next_beams(grid: &Grid, beam: Beam) -> impl Iterator<Item = Beam> + '_ {
+    let (x, y) = beam.pos;
+    let directions = match (beam.direction, grid.get(x, y)) {
+        (Direction::N | Direction::S, Some(Mirror::Horizontal)) => {
+            vec![Direction::E, Direction::W]
+        }
+        (Direction::E | Direction::W, Some(Mirror::Vertical)) => vec![Direction::N, Direction::S],
+        (Direction::E, Some(Mirror::Forward)) | (Direction::W, Some(Mirror::Backward)) => {
+            vec![Direction::S]
+        }
+        (Direction::E, Some(Mirror::Backward)) | (Direction::W, Some(Mirror::Forward)) => {
+            vec![Direction::N]
+        }
+        (Direction::N, Some(Mirror::Forward)) | (Direction::S, Some(Mirror::Backward)) => {
+            vec![Direction::W]
+        }
+        (Direction::N, Some(Mirror::Backward)) | (Direction::S, Some(Mirror::Forward)) => {
+            vec![Direction::E]
+        }
+        _ => vec![beam.direction],
+    };
+    directions
+        .into_iter()
+        .filter_map(move |direction| beam.facing(direction).ahead(grid.dimensions()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::render::Render;
+
+    #[test]
+    fn example() {
+        let input = r#"
+.|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....
+"#
+        .trim();
+        let grid = Grid::from_str(input).expect("failed to parse");
+        let energized = process_beams(&grid, Beam::new((0, 0), Direction::E));
+        let expected = r#"
+######....
+.#...#....
+.#...#####
+.#...##...
+.#...##...
+.#...##...
+.#..####..
+########..
+.#######..
+.#...#.#..
+"#
+        .trim();
+        let render = Render::new(grid.dimensions()).cells(energized.iter().copied(), '#');
+        assert_eq!(expected, render.to_string());
+        assert_eq!(46, energized.len());
+    }
+}
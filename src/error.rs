@@ -0,0 +1,232 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use crate::util::input;
+
+/// A parse failure pointing at the offending part of the input.
+///
+/// Lines and columns are stored zero based and displayed one based, with a caret under
+/// the bad text:
+///
+/// ```text
+/// line 3, column 9: expected a number
+///   Game 3: x blue
+///           ^
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub width: usize,
+    pub text: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Builds an error for `span`, which must be a slice of `src`. Working the position
+    /// out from the slice means parsers don't need to thread offsets around.
+    pub fn new(src: &str, span: &str, expected: impl Into<String>) -> Self {
+        let offset = offset_in(src, span).unwrap_or_default();
+        let (line, column, text) = position(src, offset);
+        Self {
+            line,
+            column,
+            width: span.split('\n').next().unwrap_or_default().chars().count(),
+            text: text.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Builds an error for the char at `column` of `line`.
+    pub fn at(line: &str, column: usize, expected: impl Into<String>) -> Self {
+        Self {
+            line: 0,
+            column,
+            width: 1,
+            text: line.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Builds an error just past the end of `src`, for when something is missing.
+    pub fn missing(src: &str, expected: impl Into<String>) -> Self {
+        Self::new(src, &src[src.len()..], expected)
+    }
+
+    /// Moves an error reported against `inner` so that it is relative to `outer`, which
+    /// `inner` must be a slice of.
+    pub fn rebase(self, outer: &str, inner: &str) -> Self {
+        let offset = offset_in(outer, inner).unwrap_or_default();
+        let (line_offset, column_offset, _) = position(outer, offset);
+        let line = self.line + line_offset;
+        let column = if self.line == 0 {
+            self.column + column_offset
+        } else {
+            self.column
+        };
+        let text = input::lines(outer)
+            .nth(line)
+            .map(str::to_string)
+            .unwrap_or(self.text);
+        Self {
+            line,
+            column,
+            text,
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line + 1,
+            self.column + 1,
+            self.expected
+        )?;
+        writeln!(f, "  {}", self.text)?;
+        write!(
+            f,
+            "  {}{}",
+            " ".repeat(self.column),
+            "^".repeat(self.width.max(1))
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// A failure after the input parsed fine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// The input has no answer, e.g. a maze without a loop through the start.
+    NoSolution(String),
+    /// Something the solver relies on about the input turned out not to hold.
+    Invariant(String),
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::NoSolution(msg) | SolveError::Invariant(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    Parse,
+    NoSolution,
+    Invariant,
+    Other,
+}
+
+impl ErrorKind {
+    /// Finds the most specific kind anywhere in the error's chain of causes.
+    pub fn of(error: &anyhow::Error) -> Self {
+        error
+            .chain()
+            .find_map(|cause| {
+                if cause.is::<ParseError>() {
+                    Some(ErrorKind::Parse)
+                } else {
+                    match cause.downcast_ref::<SolveError>()? {
+                        SolveError::NoSolution(_) => Some(ErrorKind::NoSolution),
+                        SolveError::Invariant(_) => Some(ErrorKind::Invariant),
+                    }
+                }
+            })
+            .unwrap_or(ErrorKind::Other)
+    }
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            ErrorKind::Parse => "parse error",
+            ErrorKind::NoSolution => "no solution",
+            ErrorKind::Invariant => "invariant violation",
+            ErrorKind::Other => "error",
+        };
+        write!(f, "{label}")
+    }
+}
+
+/// Parses every line of `raw`, reporting failures at their position in `raw`.
+pub fn parse_lines<T>(raw: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    input::parse_lines_with(raw, T::from_str)
+}
+
+/// Parses every `separator` delimited piece of `raw`, reporting failures at their
+/// position in `raw`.
+pub fn parse_split<T>(raw: &str, separator: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    raw.split(separator)
+        .map(|piece| T::from_str(piece).map_err(|e| e.rebase(raw, piece)))
+        .collect()
+}
+
+fn offset_in(outer: &str, inner: &str) -> Option<usize> {
+    let start = outer.as_ptr() as usize;
+    let offset = (inner.as_ptr() as usize).checked_sub(start)?;
+    (offset + inner.len() <= outer.len()).then_some(offset)
+}
+
+fn position(src: &str, offset: usize) -> (usize, usize, &str) {
+    let before = &src[..offset];
+    let line = before.matches('\n').count();
+    let line_start = before.rfind('\n').map(|i| i + 1).unwrap_or_default();
+    let column = src[line_start..offset].chars().count();
+    let text = input::lines(&src[line_start..]).next().unwrap_or_default();
+    (line, column, text)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn points_at_span() {
+        let src = "Game 1: 3 blue\nGame 2: x red";
+        let bad = &src[23..24];
+        let err = ParseError::new(src, bad, "a number");
+        assert_eq!((1, 8), (err.line, err.column));
+        assert_eq!(
+            "line 2, column 9: expected a number\n  Game 2: x red\n          ^",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn rebases_onto_outer() {
+        let src = "ab\ncd ef";
+        let inner = &src[6..];
+        let err = ParseError::at(inner, 1, "g").rebase(src, inner);
+        assert_eq!((1, 4, "cd ef"), (err.line, err.column, err.text.as_str()));
+    }
+
+    #[test]
+    fn kind_survives_context() {
+        use anyhow::Context;
+
+        let err = Err::<(), _>(ParseError::at("x", 0, "y"))
+            .context("failed to parse")
+            .unwrap_err();
+        assert_eq!(ErrorKind::Parse, ErrorKind::of(&err));
+        let err = anyhow::Error::from(SolveError::NoSolution("none".to_string()));
+        assert_eq!(ErrorKind::NoSolution, ErrorKind::of(&err));
+        assert_eq!(ErrorKind::Other, ErrorKind::of(&anyhow::anyhow!("other")));
+    }
+}
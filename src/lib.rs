@@ -0,0 +1,80 @@
+use std::env;
+
+use anyhow::{Context, Result};
+
+pub mod bench;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod error;
+pub mod runner;
+pub mod solution;
+pub mod source;
+pub mod util;
+pub mod verify;
+
+pub use solution::{Answer, Solution, Solved};
+
+/// A type erased entry in the calendar, so days can be picked at runtime.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub day: u8,
+    pub solve: fn(&str, &[u8]) -> Result<Solved>,
+    pub bench: fn(&str, usize) -> Result<bench::Timings>,
+}
+
+impl Day {
+    const fn of<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            solve: solution::solve::<S>,
+            bench: bench::bench::<S>,
+        }
+    }
+}
+
+pub const DAYS: [Day; 16] = [
+    Day::of::<day01::Day01>(),
+    Day::of::<day02::Day02>(),
+    Day::of::<day03::Day03>(),
+    Day::of::<day04::Day04>(),
+    Day::of::<day05::Day05>(),
+    Day::of::<day06::Day06>(),
+    Day::of::<day07::Day07>(),
+    Day::of::<day08::Day08>(),
+    Day::of::<day09::Day09>(),
+    Day::of::<day10::Day10>(),
+    Day::of::<day11::Day11>(),
+    Day::of::<day12::Day12>(),
+    Day::of::<day13::Day13>(),
+    Day::of::<day14::Day14>(),
+    Day::of::<day15::Day15>(),
+    Day::of::<day16::Day16>(),
+];
+
+pub fn find_day(day: u8) -> Option<Day> {
+    DAYS.iter().find(|d| d.day == day).copied()
+}
+
+/// Entry point for the single day binaries, which take an optional input path (or `-`).
+pub fn run(day: u8) -> Result<()> {
+    let solver = find_day(day).with_context(|| format!("no solution for day {day}"))?;
+    let input = source::load(day, env::args().nth(1).as_deref())?;
+    for solved in (solver.solve)(&input.raw, &[1, 2])?.parts {
+        println!("part {}: {}", solved.part, solved.answer);
+    }
+    Ok(())
+}
@@ -0,0 +1,128 @@
+use std::{
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, Context, Result};
+
+use crate::{
+    find_day,
+    source::{self, Source},
+    Solved,
+};
+
+/// How one day of a run went, including loading its input.
+pub struct DayReport {
+    pub day: u8,
+    pub result: Result<(Source, Solved)>,
+    pub elapsed: Duration,
+}
+
+/// Everything from a run over several days, in the order the days were asked for.
+pub struct Report {
+    pub days: Vec<DayReport>,
+    pub wall_time: Duration,
+}
+
+impl Report {
+    pub fn failures(&self) -> usize {
+        self.days.iter().filter(|d| d.result.is_err()).count()
+    }
+
+    /// Time spent across all the days, which is more than the wall time when they overlap.
+    pub fn cpu_time(&self) -> Duration {
+        self.days.iter().map(|d| d.elapsed).sum()
+    }
+}
+
+/// Runs `days` on up to `threads` worker threads. A day that fails, or even panics, is
+/// recorded in its own report and the rest carry on.
+pub fn run_days(days: &[u8], input: Option<&str>, parts: &[u8], threads: usize) -> Report {
+    let start = Instant::now();
+    let next = AtomicUsize::new(0);
+    let slots = Mutex::new((0..days.len()).map(|_| None).collect::<Vec<_>>());
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, days.len().max(1)) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(&day) = days.get(i) else {
+                    break;
+                };
+                let report = run_day(day, input, parts);
+                slots.lock().unwrap_or_else(|e| e.into_inner())[i] = Some(report);
+            });
+        }
+    });
+    let days = slots
+        .into_inner()
+        .unwrap_or_else(|e| e.into_inner())
+        .into_iter()
+        .flatten()
+        .collect();
+    Report {
+        days,
+        wall_time: start.elapsed(),
+    }
+}
+
+fn run_day(day: u8, input: Option<&str>, parts: &[u8]) -> DayReport {
+    let start = Instant::now();
+    let result =
+        catch_unwind(AssertUnwindSafe(|| solve_day(day, input, parts))).unwrap_or_else(|panic| {
+            let message = panic
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            Err(anyhow!("day {day} panicked: {message}"))
+        });
+    DayReport {
+        day,
+        result,
+        elapsed: start.elapsed(),
+    }
+}
+
+fn solve_day(day: u8, input: Option<&str>, parts: &[u8]) -> Result<(Source, Solved)> {
+    let solver = find_day(day).with_context(|| format!("no solution for day {day}"))?;
+    let input = source::load(day, input)?;
+    let solved = (solver.solve)(&input.raw, parts)?;
+    Ok((input.source, solved))
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use super::*;
+
+    #[test]
+    fn keeps_order_and_isolates_failures() {
+        let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures");
+        let days = [16, 99, 2, 15];
+        let report = run_days(&days, fixtures.join("missing").to_str(), &[1], 3);
+        assert_eq!(
+            days.to_vec(),
+            report.days.iter().map(|d| d.day).collect::<Vec<_>>()
+        );
+        assert_eq!(4, report.failures());
+
+        let report = run_days(&[2, 99], fixtures.join("02.txt").to_str(), &[1], 2);
+        let answers = report
+            .days
+            .iter()
+            .map(|d| {
+                d.result
+                    .as_ref()
+                    .map(|(_, s)| s.parts[0].answer.to_string())
+                    .ok()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(vec![Some("8".to_string()), None], answers);
+    }
+}
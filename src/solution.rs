@@ -0,0 +1,95 @@
+use std::{
+    fmt::{self, Display},
+    time::{Duration, Instant},
+};
+
+use anyhow::{bail, Result};
+
+/// A puzzle answer, keeping track of the type the solver produced it as.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => write!(f, "{n}"),
+            Answer::Unsigned(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+macro_rules! answer_from {
+    ($variant:ident($target:ty): $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::$variant(value as $target)
+                }
+            }
+        )*
+    };
+}
+
+answer_from!(Signed(i64): i32, i64);
+answer_from!(Unsigned(u64): u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+/// A single day of the calendar: parse the input once, then answer both parts from it.
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Part1: Into<Answer>;
+    type Part2: Into<Answer>;
+
+    fn parse(raw: &str) -> Result<Self::Input>;
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1>;
+
+    fn part2(input: &Self::Input) -> Result<Self::Part2>;
+}
+
+pub struct PartAnswer {
+    pub part: u8,
+    pub answer: Answer,
+    pub time: Duration,
+}
+
+pub struct Solved {
+    pub parse_time: Duration,
+    pub parts: Vec<PartAnswer>,
+}
+
+/// Parses `raw` and runs the requested parts in order, timing each step.
+pub fn solve<S: Solution>(raw: &str, parts: &[u8]) -> Result<Solved> {
+    let start = Instant::now();
+    let input = S::parse(raw)?;
+    let parse_time = start.elapsed();
+    let parts = parts
+        .iter()
+        .map(|part| {
+            let start = Instant::now();
+            let answer = match part {
+                1 => S::part1(&input)?.into(),
+                2 => S::part2(&input)?.into(),
+                p => bail!("unknown part {p}"),
+            };
+            Ok(PartAnswer {
+                part: *part,
+                answer,
+                time: start.elapsed(),
+            })
+        })
+        .collect::<Result<_>>()?;
+    Ok(Solved { parse_time, parts })
+}
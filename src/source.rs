@@ -0,0 +1,93 @@
+use std::{
+    env,
+    fmt::{self, Display},
+    fs::read_to_string,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+
+/// Directory holding `NN.txt` inputs, checked before the default locations.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where a day's input was read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+pub struct Input {
+    pub source: Source,
+    pub raw: String,
+}
+
+/// Loads the input for `day`.
+///
+/// An explicit `path` wins: `-` reads stdin, a directory is searched for `NN.txt` and
+/// anything else is read as the input file itself. Without one we try
+/// `$AOC_INPUT_DIR/NN.txt`, then `inputs/NN.txt` relative to the working directory and
+/// finally relative to the crate root.
+pub fn load(day: u8, path: Option<&str>) -> Result<Input> {
+    let file_name = format!("{day:02}.txt");
+    let candidates = match path {
+        Some("-") => {
+            let mut raw = String::new();
+            io::stdin()
+                .read_to_string(&mut raw)
+                .context("failed to read stdin")?;
+            return Ok(Input {
+                source: Source::Stdin,
+                raw,
+            });
+        }
+        Some(path) if Path::new(path).is_dir() => vec![Path::new(path).join(&file_name)],
+        Some(path) => vec![PathBuf::from(path)],
+        None => {
+            let mut candidates = vec![];
+            if let Some(dir) = env::var_os(INPUT_DIR_VAR) {
+                candidates.push(PathBuf::from(dir).join(&file_name));
+            }
+            candidates.push(Path::new("inputs").join(&file_name));
+            candidates.push(
+                Path::new(env!("CARGO_MANIFEST_DIR"))
+                    .join("inputs")
+                    .join(&file_name),
+            );
+            candidates
+        }
+    };
+
+    for candidate in &candidates {
+        if candidate.is_file() {
+            let raw = read_to_string(candidate)
+                .with_context(|| format!("failed to read {}", candidate.display()))?;
+            return Ok(Input {
+                source: Source::File(candidate.clone()),
+                raw,
+            });
+        }
+    }
+
+    let searched = candidates
+        .iter()
+        .map(|c| format!("  {}", c.display()))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let hint = if path.is_none() && env::var_os(INPUT_DIR_VAR).is_none() {
+        format!("\n(pass a path, `-` for stdin, or set {INPUT_DIR_VAR})")
+    } else {
+        String::new()
+    };
+    bail!("no input found for day {day}, searched:\n{searched}{hint}")
+}
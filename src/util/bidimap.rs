@@ -0,0 +1,456 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    ops::Bound::{Excluded, Unbounded},
+    str::FromStr,
+};
+
+use crate::error::ParseError;
+
+use super::{coord::Coord, direction::Direction, input, transform::Transform};
+
+pub enum Parsed<T> {
+    Item(T),
+    Skip,
+}
+
+/// A sparse 2D map indexed both ways, so rows and columns can be scanned without visiting
+/// empty cells. Coordinates default to `usize`; use a signed type for maps that can grow
+/// in any direction.
+#[derive(Hash, Clone, PartialEq, Eq)]
+pub struct BidiMap<T, C = usize> {
+    x: BTreeMap<C, BTreeSet<C>>,
+    y: BTreeMap<C, BTreeSet<C>>,
+    items: BTreeMap<(C, C), T>,
+    w: usize,
+    h: usize,
+}
+
+impl<T, C: Coord> BidiMap<T, C> {
+    pub fn new() -> Self {
+        Self {
+            x: BTreeMap::new(),
+            y: BTreeMap::new(),
+            items: BTreeMap::new(),
+            w: 0,
+            h: 0,
+        }
+    }
+
+    /// An empty map that reports `(w, h)` as its dimensions.
+    pub fn with_dimensions(w: usize, h: usize) -> Self {
+        Self {
+            w,
+            h,
+            ..Self::new()
+        }
+    }
+
+    pub fn insert(&mut self, x: C, y: C, item: T) {
+        self.x.entry(x).or_default().insert(y);
+        self.y.entry(y).or_default().insert(x);
+        self.items.insert((x, y), item);
+    }
+
+    pub fn remove(&mut self, x: C, y: C) -> Option<T> {
+        let item = self.items.remove(&(x, y))?;
+        remove_from_index(&mut self.x, x, y);
+        remove_from_index(&mut self.y, y, x);
+        Some(item)
+    }
+
+    pub fn get(&self, x: C, y: C) -> Option<&T> {
+        self.items.get(&(x, y))
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Every item, column by column.
+    pub fn iter_by_x(&self) -> impl DoubleEndedIterator<Item = ((C, C), &T)> + '_ {
+        self.items.iter().map(|(pos, item)| (*pos, item))
+    }
+
+    /// Every item, row by row.
+    pub fn iter_by_y(&self) -> impl DoubleEndedIterator<Item = ((C, C), &T)> + '_ {
+        self.y.keys().flat_map(|y| self.row(*y))
+    }
+
+    /// The items in row `y`, west to east.
+    pub fn row(&self, y: C) -> impl DoubleEndedIterator<Item = ((C, C), &T)> + '_ {
+        self.y
+            .get(&y)
+            .into_iter()
+            .flatten()
+            .map(move |x| self.entry(*x, y))
+    }
+
+    /// The items in column `x`, north to south.
+    pub fn column(&self, x: C) -> impl DoubleEndedIterator<Item = ((C, C), &T)> + '_ {
+        self.items
+            .range((x, C::MIN)..=(x, C::MAX))
+            .map(|(pos, item)| (*pos, item))
+    }
+
+    pub fn row_count(&self, y: C) -> usize {
+        self.y.get(&y).map_or(0, BTreeSet::len)
+    }
+
+    pub fn column_count(&self, x: C) -> usize {
+        self.x.get(&x).map_or(0, BTreeSet::len)
+    }
+
+    /// The items with both coordinates between the two corners, inclusive, column by column.
+    pub fn items_in_rect(
+        &self,
+        (x1, y1): (C, C),
+        (x2, y2): (C, C),
+    ) -> impl Iterator<Item = ((C, C), &T)> + '_ {
+        let (min_x, max_x) = (x1.min(x2), x1.max(x2));
+        let (min_y, max_y) = (y1.min(y2), y1.max(y2));
+        self.x
+            .range(min_x..=max_x)
+            .flat_map(move |(x, ys)| ys.range(min_y..=max_y).map(move |y| self.entry(*x, *y)))
+    }
+
+    /// Every item hit by a ray from `(x, y)` heading in `direction`, nearest first. The
+    /// starting point itself is not included.
+    pub fn all_after(
+        &self,
+        (x, y): (C, C),
+        direction: Direction,
+    ) -> Box<dyn Iterator<Item = ((C, C), &T)> + '_> {
+        let column = self.x.get(&x).into_iter();
+        let row = self.y.get(&y).into_iter();
+        match direction {
+            Direction::N => Box::new(
+                column
+                    .flat_map(move |ys| ys.range(..y).rev())
+                    .map(move |ny| self.entry(x, *ny)),
+            ),
+            Direction::S => Box::new(
+                column
+                    .flat_map(move |ys| ys.range((Excluded(y), Unbounded)))
+                    .map(move |ny| self.entry(x, *ny)),
+            ),
+            Direction::E => Box::new(
+                row.flat_map(move |xs| xs.range((Excluded(x), Unbounded)))
+                    .map(move |nx| self.entry(*nx, y)),
+            ),
+            Direction::W => Box::new(
+                row.flat_map(move |xs| xs.range(..x).rev())
+                    .map(move |nx| self.entry(*nx, y)),
+            ),
+        }
+    }
+
+    /// The first item a ray from `(x, y)` hits, with how many steps away it is.
+    pub fn nearest_in_direction(
+        &self,
+        (x, y): (C, C),
+        direction: Direction,
+    ) -> Option<((C, C), usize, &T)> {
+        let (first, item) = self.all_after((x, y), direction).next()?;
+        Some((first, first.0.distance(x) + first.1.distance(y), item))
+    }
+
+    pub fn first_after(&self, (x, y): (C, C), direction: Direction) -> Option<(C, C)> {
+        self.nearest_in_direction((x, y), direction)
+            .map(|(pos, _, _)| pos)
+    }
+
+    /// The smallest and largest corners of a box around every item, kept up to date as
+    /// items come and go.
+    pub fn bounds(&self) -> Option<((C, C), (C, C))> {
+        let (min_x, max_x) = (self.x.keys().next()?, self.x.keys().next_back()?);
+        let (min_y, max_y) = (self.y.keys().next()?, self.y.keys().next_back()?);
+        Some(((*min_x, *min_y), (*max_x, *max_y)))
+    }
+
+    // only for positions taken from the indexes, which always have an item
+    fn entry(&self, x: C, y: C) -> ((C, C), &T) {
+        ((x, y), &self.items[&(x, y)])
+    }
+
+    /// The size of the text the map was parsed from, which can be bigger than `bounds`
+    /// when the edges are empty.
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.w, self.h)
+    }
+}
+
+impl<T> BidiMap<T> {
+    /// Looks at the map through `transform` without copying it. Flips and rotations work
+    /// within `dimensions`.
+    pub fn view(&self, transform: Transform) -> BidiView<'_, T> {
+        BidiView {
+            map: self,
+            transform,
+        }
+    }
+
+    pub fn transformed(&self, transform: Transform) -> BidiMap<T>
+    where
+        T: Clone,
+    {
+        self.view(transform).to_map()
+    }
+}
+
+/// A transformed, read only window onto a `BidiMap`.
+#[derive(Clone, Copy)]
+pub struct BidiView<'a, T> {
+    map: &'a BidiMap<T>,
+    transform: Transform,
+}
+
+impl<'a, T> BidiView<'a, T> {
+    pub fn dimensions(&self) -> (usize, usize) {
+        self.transform.dimensions(self.map.dimensions())
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&'a T> {
+        let (w, h) = self.dimensions();
+        if x >= w || y >= h {
+            return None;
+        }
+        let (sx, sy) = self.transform.source((x, y), self.map.dimensions());
+        self.map.get(sx, sy)
+    }
+
+    /// The items in row `y` of the view, west to east.
+    pub fn row(&self, y: usize) -> Box<dyn Iterator<Item = ((usize, usize), &'a T)> + 'a> {
+        let (w, _) = self.dimensions();
+        self.line((0, y), (w.saturating_sub(1), y))
+    }
+
+    /// The items in column `x` of the view, north to south.
+    pub fn column(&self, x: usize) -> Box<dyn Iterator<Item = ((usize, usize), &'a T)> + 'a> {
+        let (_, h) = self.dimensions();
+        self.line((x, 0), (x, h.saturating_sub(1)))
+    }
+
+    /// Every item with its position in the view, row by row.
+    pub fn iter_by_y(&self) -> impl Iterator<Item = ((usize, usize), &'a T)> + '_ {
+        (0..self.dimensions().1).flat_map(|y| self.row(y))
+    }
+
+    pub fn to_map(&self) -> BidiMap<T>
+    where
+        T: Clone,
+    {
+        let (w, h) = self.dimensions();
+        let mut map = BidiMap::with_dimensions(w, h);
+        for ((x, y), item) in self.map.iter_by_x() {
+            let (tx, ty) = self.transform.apply((x, y), self.map.dimensions());
+            map.insert(tx, ty, item.clone());
+        }
+        map
+    }
+
+    // Every row or column of the view is a row or column of the map, possibly reversed,
+    // so it can be read straight from the map's indexes.
+    fn line(
+        &self,
+        start: (usize, usize),
+        end: (usize, usize),
+    ) -> Box<dyn Iterator<Item = ((usize, usize), &'a T)> + 'a> {
+        let (w, h) = self.dimensions();
+        if start.0 >= w || start.1 >= h {
+            return Box::new(std::iter::empty());
+        }
+        let size = self.map.dimensions();
+        let (from, to) = (
+            self.transform.source(start, size),
+            self.transform.source(end, size),
+        );
+        let transform = self.transform;
+        if from == to {
+            // a single cell says nothing about whether it sits in a row or a column
+            let item = self.map.get(from.0, from.1);
+            return Box::new(item.map(|item| (start, item)).into_iter());
+        }
+        let items: MapLine<'a, T> = if from.1 == to.1 {
+            Box::new(self.map.row(from.1))
+        } else {
+            Box::new(self.map.column(from.0))
+        };
+        let forward = move |(pos, item)| (transform.apply(pos, size), item);
+        if from <= to {
+            Box::new(items.map(forward))
+        } else {
+            Box::new(items.rev().map(forward))
+        }
+    }
+}
+
+type MapLine<'a, T> = Box<dyn DoubleEndedIterator<Item = ((usize, usize), &'a T)> + 'a>;
+
+fn remove_from_index<C: Coord>(index: &mut BTreeMap<C, BTreeSet<C>>, key: C, value: C) {
+    if let Some(values) = index.get_mut(&key) {
+        values.remove(&value);
+        if values.is_empty() {
+            index.remove(&key);
+        }
+    }
+}
+
+impl<T, C: Coord> Default for BidiMap<T, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, C: Coord> FromStr for BidiMap<T, C>
+where
+    Parsed<T>: TryFrom<char>,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut map = Self::new();
+        let s = s.trim();
+        for (y, line) in input::lines(s).enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                if let Parsed::Item(item) = Parsed::<T>::try_from(c)
+                    .map_err(|_| ParseError::at(line, x, "a known map character").rebase(s, line))?
+                {
+                    map.insert(C::from_index(x), C::from_index(y), item);
+                }
+                map.w = x + 1;
+            }
+            map.h = y + 1;
+        }
+        Ok(map)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sample() -> BidiMap<char> {
+        let mut map = BidiMap::new();
+        for (x, y, c) in [
+            (1, 0, 'a'),
+            (3, 0, 'b'),
+            (1, 2, 'c'),
+            (1, 4, 'd'),
+            (2, 2, 'e'),
+        ] {
+            map.insert(x, y, c);
+        }
+        map
+    }
+
+    #[test]
+    fn rows_columns_and_rects() {
+        let map = sample();
+        let row = map.row(0).map(|(_, c)| *c).collect::<String>();
+        let column = map.column(1).map(|(_, c)| *c).collect::<String>();
+        assert_eq!(("ab", "acd"), (row.as_str(), column.as_str()));
+        assert_eq!(
+            (2, 3, 0),
+            (map.row_count(0), map.column_count(1), map.row_count(1))
+        );
+        let rect = map
+            .items_in_rect((2, 0), (1, 2))
+            .map(|(_, c)| *c)
+            .collect::<String>();
+        assert_eq!("ace", rect);
+    }
+
+    #[test]
+    fn ray_hits() {
+        let mut map = sample();
+        assert_eq!(
+            Some(((1, 2), 2, &'c')),
+            map.nearest_in_direction((1, 0), Direction::S)
+        );
+        let hits = map
+            .all_after((1, 5), Direction::N)
+            .map(|(p, _)| p)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![(1, 4), (1, 2), (1, 0)], hits);
+        assert_eq!(None, map.nearest_in_direction((1, 2), Direction::W));
+        map.remove(1, 2);
+        assert_eq!(
+            Some(((1, 4), 4, &'d')),
+            map.nearest_in_direction((1, 0), Direction::S)
+        );
+        assert_eq!(1, map.row_count(2));
+    }
+
+    #[test]
+    fn signed_coordinates_grow_bounds() {
+        let mut map = BidiMap::<char, i64>::new();
+        assert_eq!(None, map.bounds());
+        let mut pos = (0, 0);
+        for direction in [Direction::W, Direction::N, Direction::N, Direction::E] {
+            map.insert(pos.0, pos.1, '#');
+            pos = direction.step(pos).unwrap();
+        }
+        map.insert(pos.0, pos.1, '#');
+        assert_eq!(Some(((-1, -2), (0, 0))), map.bounds());
+        assert_eq!(
+            Some(((-1, -1), 1, &'#')),
+            map.nearest_in_direction((-1, -2), Direction::S)
+        );
+        map.remove(0, 0);
+        map.remove(0, -2);
+        assert_eq!(Some(((-1, -2), (-1, 0))), map.bounds());
+    }
+
+    #[test]
+    fn views_match_copies() {
+        let mut map = BidiMap::with_dimensions(3, 2);
+        map.insert(0, 0, 'a');
+        map.insert(2, 0, 'b');
+        map.insert(1, 1, 'c');
+        let rotated = map.transformed(Transform::Rotate90);
+        assert_eq!((2, 3), rotated.dimensions());
+        let cells = rotated
+            .iter_by_y()
+            .map(|(p, c)| (p, *c))
+            .collect::<Vec<_>>();
+        assert_eq!(vec![((1, 0), 'a'), ((0, 1), 'c'), ((1, 2), 'b')], cells);
+
+        let view = map.view(Transform::Rotate90);
+        let viewed = view.iter_by_y().map(|(p, c)| (p, *c)).collect::<Vec<_>>();
+        assert_eq!(cells, viewed);
+        assert_eq!(Some(&'b'), view.get(1, 2));
+        let column = view.column(1).map(|(_, c)| *c).collect::<String>();
+        assert_eq!("ab", column);
+
+        // one cell wide or tall, where a line's two ends are the same cell
+        let mut row = BidiMap::with_dimensions(3, 1);
+        row.insert(0, 0, 'a');
+        row.insert(1, 0, 'b');
+        row.insert(2, 0, 'c');
+        for transform in [
+            Transform::Rotate90,
+            Transform::Transpose,
+            Transform::FlipHorizontal,
+        ] {
+            let view = row.view(transform);
+            let copy = row.transformed(transform);
+            let (w, h) = view.dimensions();
+            for y in 0..h {
+                let viewed = view.row(y).map(|(p, c)| (p, *c)).collect::<Vec<_>>();
+                let copied = copy.row(y).map(|(p, c)| (p, *c)).collect::<Vec<_>>();
+                assert_eq!(copied, viewed, "{transform:?} row {y}");
+            }
+            for x in 0..w {
+                let viewed = view.column(x).map(|(p, c)| (p, *c)).collect::<Vec<_>>();
+                let copied = copy.column(x).map(|(p, c)| (p, *c)).collect::<Vec<_>>();
+                assert_eq!(copied, viewed, "{transform:?} column {x}");
+            }
+        }
+        let rotated = row.view(Transform::Rotate90);
+        assert_eq!(vec![((0, 1), &'b')], rotated.row(1).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,41 @@
+use std::{fmt::Debug, hash::Hash};
+
+/// An integer type usable as a map coordinate. Unsigned coordinates stop at zero, signed
+/// ones can go anywhere.
+pub trait Coord: Copy + Ord + Hash + Debug {
+    const MIN: Self;
+    const MAX: Self;
+
+    /// Converts a column or line number from parsed text.
+    fn from_index(index: usize) -> Self;
+
+    /// Moves by `delta`, or `None` if that leaves the type's range.
+    fn offset(self, delta: i32) -> Option<Self>;
+
+    fn distance(self, other: Self) -> usize;
+}
+
+macro_rules! coord {
+    ($($t:ty: $add:ident),*) => {
+        $(
+            impl Coord for $t {
+                const MIN: Self = <$t>::MIN;
+                const MAX: Self = <$t>::MAX;
+
+                fn from_index(index: usize) -> Self {
+                    index as $t
+                }
+
+                fn offset(self, delta: i32) -> Option<Self> {
+                    self.$add(delta as _)
+                }
+
+                fn distance(self, other: Self) -> usize {
+                    self.abs_diff(other) as usize
+                }
+            }
+        )*
+    };
+}
+
+coord!(usize: checked_add_signed, i32: checked_add, i64: checked_add, isize: checked_add);
@@ -0,0 +1,154 @@
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    convert::Infallible,
+    hash::{Hash, Hasher},
+};
+
+/// A sequence of states that runs through `prefix` states once and then repeats the next
+/// `period` forever.
+#[derive(Debug)]
+pub struct Cycle<S> {
+    pub prefix: usize,
+    pub period: usize,
+    /// Every state up to the first repeat.
+    states: Vec<S>,
+}
+
+impl<S> Cycle<S> {
+    /// The state after `n` steps.
+    pub fn state_at(&self, n: usize) -> &S {
+        &self.states[self.index_of(n)]
+    }
+
+    /// Which of the first `prefix + period` steps has the same state as step `n`.
+    pub fn index_of(&self, n: usize) -> usize {
+        if n < self.prefix {
+            n
+        } else {
+            self.prefix + (n - self.prefix) % self.period
+        }
+    }
+
+    /// The states before the loop starts followed by one pass round it.
+    pub fn states(&self) -> &[S] {
+        &self.states
+    }
+
+    /// The states that repeat forever.
+    pub fn repeating(&self) -> &[S] {
+        &self.states[self.prefix..]
+    }
+}
+
+/// Steps from `initial` until a state comes round again. States are compared exactly, so
+/// this only stops for a genuine repeat.
+pub fn find<S>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle<S>
+where
+    S: Eq + Hash,
+{
+    let Ok(cycle) = try_find(initial, |state| Ok::<_, Infallible>(step(state)));
+    cycle
+}
+
+/// Like `find`, but stops at the first error `step` returns.
+pub fn try_find<S, E>(initial: S, step: impl FnMut(&S) -> Result<S, E>) -> Result<Cycle<S>, E>
+where
+    S: Eq + Hash,
+{
+    try_find_by_key(initial, step, hash, true)
+}
+
+/// Detects a repeat by comparing `key`s, which can be cheaper to store and compare than
+/// whole states. If `verify` is set the states behind equal keys must also be equal, so a
+/// lossy key like a hash can't cause a false repeat; otherwise equal keys are trusted.
+pub fn find_by_key<S, K>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+    key: impl FnMut(&S) -> K,
+    verify: bool,
+) -> Cycle<S>
+where
+    S: PartialEq,
+    K: Eq + Hash,
+{
+    let Ok(cycle) = try_find_by_key(
+        initial,
+        |state| Ok::<_, Infallible>(step(state)),
+        key,
+        verify,
+    );
+    cycle
+}
+
+fn try_find_by_key<S, K, E>(
+    initial: S,
+    mut step: impl FnMut(&S) -> Result<S, E>,
+    mut key: impl FnMut(&S) -> K,
+    verify: bool,
+) -> Result<Cycle<S>, E>
+where
+    S: PartialEq,
+    K: Eq + Hash,
+{
+    // several states can share a key when it is lossy
+    let mut seen: HashMap<K, Vec<usize>> = HashMap::new();
+    let mut states = vec![];
+    let mut state = initial;
+    loop {
+        let candidates = seen.entry(key(&state)).or_default();
+        let repeat = candidates
+            .iter()
+            .copied()
+            .find(|&i| !verify || states[i] == state);
+        if let Some(prefix) = repeat {
+            return Ok(Cycle {
+                prefix,
+                period: states.len() - prefix,
+                states,
+            });
+        }
+        candidates.push(states.len());
+        let next = step(&state)?;
+        states.push(state);
+        state = next;
+    }
+}
+
+fn hash<S: Hash>(state: &S) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    state.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn finds_prefix_and_period() {
+        // 1, 2, 4, 8, 16, 12, 4, ...
+        let cycle = find(1, |n| n * 2 % 20);
+        assert_eq!((2, 4), (cycle.prefix, cycle.period));
+        assert_eq!(&[1, 2, 4, 8, 16, 12], cycle.states());
+        assert_eq!(&[4, 8, 16, 12], cycle.repeating());
+        assert_eq!(&8, cycle.state_at(3));
+        assert_eq!(&16, cycle.state_at(1_000_000_000));
+        assert_eq!(4, cycle.index_of(1_000_000_000));
+    }
+
+    #[test]
+    fn verification_rejects_key_collisions() {
+        // keys repeat every 3 steps but the states never do until 6
+        let step = |n: &usize| (n + 1) % 6;
+        let trusting = find_by_key(0, step, |n| n % 3, false);
+        assert_eq!((0, 3), (trusting.prefix, trusting.period));
+        let verified = find_by_key(0, step, |n| n % 3, true);
+        assert_eq!((0, 6), (verified.prefix, verified.period));
+    }
+
+    #[test]
+    fn stops_on_errors() {
+        let result = try_find(0, |&n| if n < 3 { Ok(n + 1) } else { Err(n) });
+        assert_eq!(3, result.unwrap_err());
+    }
+}
@@ -0,0 +1,346 @@
+use std::fmt::{self, Display};
+
+use super::coord::Coord;
+
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub enum Direction {
+    N,
+    S,
+    E,
+    W,
+}
+
+/// A change of heading relative to the current one.
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub enum Turn {
+    Left,
+    Right,
+    Back,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::N, Direction::S, Direction::E, Direction::W];
+
+    pub fn offset(&self) -> (i32, i32) {
+        match self {
+            Direction::N => (0, -1),
+            Direction::S => (0, 1),
+            Direction::E => (1, 0),
+            Direction::W => (-1, 0),
+        }
+    }
+
+    pub fn is_opposite(&self, other: Direction) -> bool {
+        self.opposite() == other
+    }
+
+    pub fn left(&self) -> Self {
+        match self {
+            Direction::N => Direction::W,
+            Direction::W => Direction::S,
+            Direction::S => Direction::E,
+            Direction::E => Direction::N,
+        }
+    }
+
+    pub fn right(&self) -> Self {
+        match self {
+            Direction::N => Direction::E,
+            Direction::E => Direction::S,
+            Direction::S => Direction::W,
+            Direction::W => Direction::N,
+        }
+    }
+
+    pub fn opposite(&self) -> Self {
+        self.right().right()
+    }
+
+    pub fn turn(&self, turn: Turn) -> Self {
+        match turn {
+            Turn::Left => self.left(),
+            Turn::Right => self.right(),
+            Turn::Back => self.opposite(),
+        }
+    }
+
+    pub fn min(&self, p1: (usize, usize), p2: (usize, usize)) -> (usize, usize) {
+        match (self, p1, p2) {
+            (Direction::N, (_, y1), (_, y2)) => {
+                if y1 > y2 {
+                    p1
+                } else {
+                    p2
+                }
+            }
+            (Direction::S, (_, y1), (_, y2)) => {
+                if y1 > y2 {
+                    p2
+                } else {
+                    p1
+                }
+            }
+            (Direction::E, (x1, _), (x2, _)) => {
+                if x1 > x2 {
+                    p2
+                } else {
+                    p1
+                }
+            }
+            (Direction::W, (x1, _), (x2, _)) => {
+                if x1 > x2 {
+                    p1
+                } else {
+                    p2
+                }
+            }
+        }
+    }
+
+    /// One step from `(x, y)`, limited only by what the coordinate type can hold. See
+    /// `move_fowrard` for staying inside a fixed size map.
+    pub fn step<C: Coord>(self, (x, y): (C, C)) -> Option<(C, C)> {
+        let (dx, dy) = self.offset();
+        Some((x.offset(dx)?, y.offset(dy)?))
+    }
+
+    pub fn move_fowrard(
+        self,
+        (x, y): (usize, usize),
+        (mx, my): (usize, usize),
+    ) -> Option<(usize, usize)> {
+        let (dx, dy) = self.offset();
+        let (nx, ny) = (x as i32 + dx, y as i32 + dy);
+        if nx < 0 || nx >= mx as i32 || ny < 0 || ny >= my as i32 {
+            None
+        } else {
+            Some((nx as usize, ny as usize))
+        }
+    }
+}
+
+/// Accepts compass letters (`NSEW`), screen directions (`UDLR`) and arrows (`^v<>`).
+impl TryFrom<char> for Direction {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'N' | 'U' | '^' => Ok(Direction::N),
+            'S' | 'D' | 'v' => Ok(Direction::S),
+            'E' | 'R' | '>' => Ok(Direction::E),
+            'W' | 'L' | '<' => Ok(Direction::W),
+            c => Err(c),
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Direction::N => 'N',
+            Direction::S => 'S',
+            Direction::E => 'E',
+            Direction::W => 'W',
+        };
+        write!(f, "{c}")
+    }
+}
+
+impl TryFrom<char> for Turn {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'L' => Ok(Turn::Left),
+            'R' => Ok(Turn::Right),
+            'B' => Ok(Turn::Back),
+            c => Err(c),
+        }
+    }
+}
+
+/// A compass direction including the diagonals, for when corners count as neighbours.
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub enum Direction8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction8 {
+    /// Clockwise from north.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::N,
+        Direction8::NE,
+        Direction8::E,
+        Direction8::SE,
+        Direction8::S,
+        Direction8::SW,
+        Direction8::W,
+        Direction8::NW,
+    ];
+
+    pub fn offset(&self) -> (i32, i32) {
+        match self {
+            Direction8::N => (0, -1),
+            Direction8::NE => (1, -1),
+            Direction8::E => (1, 0),
+            Direction8::SE => (1, 1),
+            Direction8::S => (0, 1),
+            Direction8::SW => (-1, 1),
+            Direction8::W => (-1, 0),
+            Direction8::NW => (-1, -1),
+        }
+    }
+
+    fn rotate(self, eighths: usize) -> Self {
+        // variants are declared in the same clockwise order as ALL
+        Self::ALL[(self as usize + eighths) % 8]
+    }
+
+    /// 45° anticlockwise.
+    pub fn left_45(&self) -> Self {
+        self.rotate(7)
+    }
+
+    /// 45° clockwise.
+    pub fn right_45(&self) -> Self {
+        self.rotate(1)
+    }
+
+    pub fn left(&self) -> Self {
+        self.rotate(6)
+    }
+
+    pub fn right(&self) -> Self {
+        self.rotate(2)
+    }
+
+    pub fn opposite(&self) -> Self {
+        self.rotate(4)
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        !matches!(
+            self,
+            Direction8::N | Direction8::E | Direction8::S | Direction8::W
+        )
+    }
+
+    /// One step from `(x, y)`, limited only by what the coordinate type can hold.
+    pub fn step<C: Coord>(self, (x, y): (C, C)) -> Option<(C, C)> {
+        let (dx, dy) = self.offset();
+        Some((x.offset(dx)?, y.offset(dy)?))
+    }
+
+    /// One step from `(x, y)`, or `None` if that leaves a `mx` by `my` map.
+    pub fn move_forward(
+        self,
+        (x, y): (usize, usize),
+        (mx, my): (usize, usize),
+    ) -> Option<(usize, usize)> {
+        self.step((x, y)).filter(|(nx, ny)| *nx < mx && *ny < my)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(value: Direction) -> Self {
+        match value {
+            Direction::N => Direction8::N,
+            Direction::S => Direction8::S,
+            Direction::E => Direction8::E,
+            Direction::W => Direction8::W,
+        }
+    }
+}
+
+impl TryFrom<Direction8> for Direction {
+    type Error = Direction8;
+
+    fn try_from(value: Direction8) -> Result<Self, Self::Error> {
+        match value {
+            Direction8::N => Ok(Direction::N),
+            Direction8::S => Ok(Direction::S),
+            Direction8::E => Ok(Direction::E),
+            Direction8::W => Ok(Direction::W),
+            diagonal => Err(diagonal),
+        }
+    }
+}
+
+/// The orthogonal neighbours of `(x, y)` that are inside a `mx` by `my` map.
+pub fn von_neumann(
+    (x, y): (usize, usize),
+    (mx, my): (usize, usize),
+) -> impl Iterator<Item = (usize, usize)> {
+    Direction8::ALL
+        .into_iter()
+        .filter(|d| !d.is_diagonal())
+        .filter_map(move |d| d.move_forward((x, y), (mx, my)))
+}
+
+/// The orthogonal and diagonal neighbours of `(x, y)` that are inside a `mx` by `my` map.
+pub fn moore(
+    (x, y): (usize, usize),
+    (mx, my): (usize, usize),
+) -> impl Iterator<Item = (usize, usize)> {
+    Direction8::ALL
+        .into_iter()
+        .filter_map(move |d| d.move_forward((x, y), (mx, my)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rotations_and_conversions() {
+        assert_eq!(Direction8::NW, Direction8::N.left_45());
+        assert_eq!(Direction8::SW, Direction8::NW.left());
+        assert_eq!(Direction8::N, Direction8::W.right());
+        assert_eq!(Direction8::SE, Direction8::NW.opposite());
+        assert_eq!(
+            Ok(Direction::E),
+            Direction::try_from(Direction8::from(Direction::E))
+        );
+        assert_eq!(Err(Direction8::NE), Direction::try_from(Direction8::NE));
+    }
+
+    #[test]
+    fn parses_and_turns() {
+        let parsed = "NvLR>^"
+            .chars()
+            .map(Direction::try_from)
+            .collect::<Result<Vec<_>, _>>();
+        let expected = [
+            Direction::N,
+            Direction::S,
+            Direction::W,
+            Direction::E,
+            Direction::E,
+            Direction::N,
+        ];
+        assert_eq!(Ok(expected.to_vec()), parsed);
+        assert_eq!(Err('x'), Direction::try_from('x'));
+        assert_eq!("NSEW", Direction::ALL.map(|d| d.to_string()).concat());
+        assert_eq!(Direction::W, Direction::N.turn(Turn::Left));
+        assert_eq!(Direction::S, Direction::W.turn(Turn::Left));
+        assert_eq!(Direction::E, Direction::W.turn(Turn::Back));
+    }
+
+    #[test]
+    fn neighbourhoods_stay_in_bounds() {
+        assert_eq!(
+            vec![(1, 0), (0, 1)],
+            von_neumann((0, 0), (3, 3)).collect::<Vec<_>>()
+        );
+        assert_eq!(3, moore((0, 0), (3, 3)).count());
+        assert_eq!(8, moore((1, 1), (3, 3)).count());
+        assert_eq!(5, moore((2, 1), (3, 3)).count());
+    }
+}
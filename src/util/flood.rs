@@ -0,0 +1,223 @@
+use super::{
+    direction::{moore, von_neumann},
+    grid::Grid,
+};
+
+/// Which cells count as touching.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Connectivity {
+    /// Only cells sharing an edge.
+    Four,
+    /// Cells sharing an edge or a corner.
+    Eight,
+}
+
+impl Connectivity {
+    /// The neighbours of `pos` inside a `w` by `h` map.
+    pub fn neighbours(
+        self,
+        pos: (usize, usize),
+        dimensions: (usize, usize),
+    ) -> Box<dyn Iterator<Item = (usize, usize)>> {
+        match self {
+            Connectivity::Four => Box::new(von_neumann(pos, dimensions)),
+            Connectivity::Eight => Box::new(moore(pos, dimensions)),
+        }
+    }
+}
+
+/// The cells reachable from `starts` through cells where `passable` holds, in the order they
+/// were found. Starts that aren't passable are ignored. Uses an explicit stack, so large
+/// areas can't overflow the call stack.
+pub fn flood_fill(
+    starts: impl IntoIterator<Item = (usize, usize)>,
+    dimensions: (usize, usize),
+    connectivity: Connectivity,
+    passable: impl FnMut((usize, usize)) -> bool,
+) -> Vec<(usize, usize)> {
+    let (w, h) = dimensions;
+    fill(&mut Grid::new(w, h, false), starts, connectivity, passable)
+}
+
+/// `flood_fill` that skips cells already marked in `seen` and marks the ones it fills, so
+/// several fills can share the work of one pass over the map.
+fn fill(
+    seen: &mut Grid<bool>,
+    starts: impl IntoIterator<Item = (usize, usize)>,
+    connectivity: Connectivity,
+    mut passable: impl FnMut((usize, usize)) -> bool,
+) -> Vec<(usize, usize)> {
+    let dimensions = seen.dimensions();
+    let mut stack = vec![];
+    for start in starts {
+        if seen.contains(start.0, start.1) && !seen[start] && passable(start) {
+            seen[start] = true;
+            stack.push(start);
+        }
+    }
+    let mut filled = vec![];
+    while let Some(pos) = stack.pop() {
+        filled.push(pos);
+        for next in connectivity.neighbours(pos, dimensions) {
+            if !seen[next] && passable(next) {
+                seen[next] = true;
+                stack.push(next);
+            }
+        }
+    }
+    filled
+}
+
+/// One connected area found by `label`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Region {
+    pub size: usize,
+    /// The top left and bottom right cells of the smallest rectangle holding the region.
+    pub bounds: ((usize, usize), (usize, usize)),
+}
+
+/// Every connected area of passable cells, each numbered in the order its top left most
+/// cell appears in reading order.
+pub struct Regions {
+    labels: Grid<Option<usize>>,
+    regions: Vec<Region>,
+}
+
+impl Regions {
+    /// Which region `(x, y)` belongs to, or `None` if it isn't passable.
+    pub fn label(&self, x: usize, y: usize) -> Option<usize> {
+        self.labels.get(x, y).copied().flatten()
+    }
+
+    pub fn labels(&self) -> &Grid<Option<usize>> {
+        &self.labels
+    }
+
+    pub fn region(&self, label: usize) -> Option<&Region> {
+        self.regions.get(label)
+    }
+
+    pub fn regions(&self) -> &[Region] {
+        &self.regions
+    }
+
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+}
+
+/// Splits the passable cells of a `w` by `h` map into connected regions.
+pub fn label(
+    dimensions: (usize, usize),
+    connectivity: Connectivity,
+    mut passable: impl FnMut((usize, usize)) -> bool,
+) -> Regions {
+    let (w, h) = dimensions;
+    let mut labels = Grid::new(w, h, None);
+    let mut seen = Grid::new(w, h, false);
+    let mut regions = vec![];
+    for y in 0..h {
+        for x in 0..w {
+            if seen[(x, y)] {
+                continue;
+            }
+            let filled = fill(&mut seen, [(x, y)], connectivity, &mut passable);
+            if filled.is_empty() {
+                continue;
+            }
+            let (mut min, mut max) = ((x, y), (x, y));
+            for &(fx, fy) in &filled {
+                labels[(fx, fy)] = Some(regions.len());
+                min = (min.0.min(fx), min.1.min(fy));
+                max = (max.0.max(fx), max.1.max(fy));
+            }
+            regions.push(Region {
+                size: filled.len(),
+                bounds: (min, max),
+            });
+        }
+    }
+    Regions { labels, regions }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn walls(rows: &[&str]) -> Grid<bool> {
+        Grid::from_rows(
+            rows.iter()
+                .map(|row| row.chars().map(|c| c == '#').collect())
+                .collect(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn fills_without_recursing() {
+        // a long snake would need one stack frame per cell when done recursively
+        let (w, h) = (1000, 1000);
+        let wall = |(x, y): (usize, usize)| y % 2 == 1 && x != if y % 4 == 1 { w - 1 } else { 0 };
+        let filled = flood_fill([(0, 0)], (w, h), Connectivity::Four, |pos| !wall(pos));
+        assert_eq!(w * h / 2 + h / 2, filled.len());
+    }
+
+    #[test]
+    fn corners_join_with_eight_way_connectivity() {
+        let map = walls(&["#..", ".#.", "..#"]);
+        let open = |pos| !map[pos];
+        let four = flood_fill([(1, 0)], map.dimensions(), Connectivity::Four, open);
+        assert_eq!(3, four.len());
+        let eight = flood_fill([(1, 0)], map.dimensions(), Connectivity::Eight, open);
+        assert_eq!(6, eight.len());
+        assert!(
+            flood_fill([(0, 0), (5, 5)], map.dimensions(), Connectivity::Four, open).is_empty()
+        );
+    }
+
+    #[test]
+    fn labels_regions_with_sizes_and_bounds() {
+        let map = walls(&["..#..", "###..", "...#.", ".#.#."]);
+        let regions = label(map.dimensions(), Connectivity::Four, |pos| !map[pos]);
+        assert_eq!(3, regions.len());
+        assert_eq!(
+            &[
+                Region {
+                    size: 2,
+                    bounds: ((0, 0), (1, 0))
+                },
+                Region {
+                    size: 6,
+                    bounds: ((3, 0), (4, 3))
+                },
+                Region {
+                    size: 5,
+                    bounds: ((0, 2), (2, 3))
+                },
+            ],
+            regions.regions()
+        );
+        assert_eq!(Some(1), regions.label(4, 3));
+        assert_eq!(None, regions.label(2, 0));
+        let diagonal = label(map.dimensions(), Connectivity::Eight, |pos| !map[pos]);
+        assert_eq!(2, diagonal.len());
+    }
+
+    #[test]
+    fn labels_many_regions_in_one_pass() {
+        // every open cell of a checkerboard is its own region, which is slow if each fill
+        // starts from a fresh map
+        let (w, h) = (600, 600);
+        let regions = label((w, h), Connectivity::Four, |(x, y)| (x + y) % 2 == 0);
+        assert_eq!(w * h / 2, regions.len());
+        assert!(regions.regions().iter().all(|region| region.size == 1));
+        assert_eq!(Some(w / 2), regions.label(1, 1));
+        assert_eq!(None, regions.label(1, 0));
+        let diagonal = label((w, h), Connectivity::Eight, |(x, y)| (x + y) % 2 == 0);
+        assert_eq!(1, diagonal.len());
+    }
+}
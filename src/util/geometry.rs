@@ -0,0 +1,134 @@
+//! Lattice polygons given as their corners in order, using screen coordinates where y grows
+//! downwards. The last corner joins back up to the first.
+
+use num::integer::gcd;
+
+use super::direction::Direction;
+
+pub type Point = (i64, i64);
+
+/// Which way a polygon's corners go round, as seen on screen.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Orientation {
+    Clockwise,
+    Anticlockwise,
+    /// Encloses no area.
+    Degenerate,
+}
+
+/// The corners visited by following `steps` from `start`.
+pub fn vertices_from_steps(
+    start: Point,
+    steps: impl IntoIterator<Item = (Direction, i64)>,
+) -> Vec<Point> {
+    let mut vertices = vec![start];
+    let mut current = start;
+    for (direction, length) in steps {
+        let (dx, dy) = direction.offset();
+        current = (
+            current.0 + dx as i64 * length,
+            current.1 + dy as i64 * length,
+        );
+        vertices.push(current);
+    }
+    if vertices.len() > 1 && vertices.last() == vertices.first() {
+        vertices.pop();
+    }
+    vertices
+}
+
+fn edges(vertices: &[Point]) -> impl Iterator<Item = (Point, Point)> + '_ {
+    vertices
+        .iter()
+        .copied()
+        .zip(vertices.iter().copied().cycle().skip(1))
+}
+
+/// Twice the area enclosed, by the shoelace formula, so it stays a whole number. Positive
+/// when the corners go clockwise on screen.
+pub fn double_signed_area(vertices: &[Point]) -> i64 {
+    edges(vertices)
+        .map(|((x1, y1), (x2, y2))| x1 * y2 - x2 * y1)
+        .sum()
+}
+
+pub fn area(vertices: &[Point]) -> f64 {
+    double_signed_area(vertices).abs() as f64 / 2.0
+}
+
+pub fn orientation(vertices: &[Point]) -> Orientation {
+    match double_signed_area(vertices).signum() {
+        1 => Orientation::Clockwise,
+        -1 => Orientation::Anticlockwise,
+        _ => Orientation::Degenerate,
+    }
+}
+
+/// How many lattice points lie on the edges. For edges along the axes this is also the
+/// length of the boundary.
+pub fn boundary_length(vertices: &[Point]) -> u64 {
+    edges(vertices)
+        .map(|((x1, y1), (x2, y2))| gcd(x2 - x1, y2 - y1).unsigned_abs())
+        .sum()
+}
+
+/// How many lattice points lie strictly inside, by Pick's theorem. On a grid where the
+/// polygon runs through the middle of cells, that's the number of cells it encloses.
+pub fn interior_points(vertices: &[Point]) -> u64 {
+    let area = double_signed_area(vertices).unsigned_abs();
+    // Pick's theorem only holds for shapes that enclose something
+    if area == 0 {
+        return 0;
+    }
+    // A = i + b/2 - 1
+    (area + 2).saturating_sub(boundary_length(vertices)) / 2
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn square_in_both_directions() {
+        let square = [(0, 0), (3, 0), (3, 3), (0, 3)];
+        assert_eq!(18, double_signed_area(&square));
+        assert_eq!(9.0, area(&square));
+        assert_eq!(Orientation::Clockwise, orientation(&square));
+        assert_eq!(12, boundary_length(&square));
+        assert_eq!(4, interior_points(&square));
+
+        let reversed: Vec<_> = square.iter().rev().copied().collect();
+        assert_eq!(-18, double_signed_area(&reversed));
+        assert_eq!(Orientation::Anticlockwise, orientation(&reversed));
+        assert_eq!(4, interior_points(&reversed));
+    }
+
+    #[test]
+    fn steps_and_slanted_edges() {
+        // an L shape: 3x3 square missing its top right 2x2 corner
+        let steps = [
+            (Direction::E, 1),
+            (Direction::S, 2),
+            (Direction::E, 2),
+            (Direction::S, 1),
+            (Direction::W, 3),
+            (Direction::N, 3),
+        ];
+        let shape = vertices_from_steps((0, 0), steps);
+        assert_eq!(6, shape.len());
+        assert_eq!(10, double_signed_area(&shape));
+        assert_eq!(12, boundary_length(&shape));
+        assert_eq!(0, interior_points(&shape));
+
+        let triangle = [(0, 0), (4, 0), (0, 4)];
+        assert_eq!(12, boundary_length(&triangle));
+        assert_eq!(3, interior_points(&triangle));
+        assert_eq!(
+            Orientation::Degenerate,
+            orientation(&[(0, 0), (2, 2), (4, 4)])
+        );
+        assert_eq!(0, interior_points(&[(0, 0), (2, 0)]));
+        assert_eq!(0, interior_points(&[(0, 0), (2, 2), (4, 4)]));
+        assert_eq!(0, interior_points(&[(1, 1)]));
+    }
+}
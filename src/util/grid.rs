@@ -0,0 +1,336 @@
+use std::{
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use crate::error::ParseError;
+
+use super::{bidimap::Parsed, direction::Direction, input, transform::Transform};
+
+/// A dense, row major 2D grid indexed by `(x, y)`, for maps where most cells matter.
+/// `BidiMap` is the sparse alternative.
+#[derive(Debug, Hash, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    w: usize,
+    h: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(w: usize, h: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![fill; w * h],
+            w,
+            h,
+        }
+    }
+
+    /// Builds a grid from rows, which must all be as wide as the first.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let h = rows.len();
+        let w = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != w) {
+            return None;
+        }
+        Some(Self {
+            cells: rows.into_iter().flatten().collect(),
+            w,
+            h,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.w
+    }
+
+    pub fn height(&self) -> usize {
+        self.h
+    }
+
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.w, self.h)
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.w && y < self.h
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.contains(x, y).then(|| &self.cells[y * self.w + x])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.contains(x, y).then(|| &mut self.cells[y * self.w + x])
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.h).then(|| &self.cells[y * self.w..(y + 1) * self.w])
+    }
+
+    pub fn column(&self, x: usize) -> Option<impl DoubleEndedIterator<Item = &T> + '_> {
+        (x < self.w).then(|| self.cells.iter().skip(x).step_by(self.w))
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + '_ {
+        // chunks panics on 0, and an empty grid has no rows anyway
+        self.cells.chunks(self.w.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T> + '_> + '_ {
+        (0..self.w).flat_map(|x| self.column(x))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, item)| ((i % self.w, i / self.w), item))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.h).flat_map(|y| (0..self.w).map(move |x| (x, y)))
+    }
+
+    /// The position one step from `(x, y)` in `direction`, if it is still on the grid.
+    pub fn neighbour(
+        &self,
+        (x, y): (usize, usize),
+        direction: Direction,
+    ) -> Option<(usize, usize)> {
+        direction.move_fowrard((x, y), (self.w, self.h))
+    }
+
+    /// The on-grid cells next to `(x, y)` with the direction they lie in.
+    pub fn neighbours(
+        &self,
+        (x, y): (usize, usize),
+    ) -> impl Iterator<Item = (Direction, (usize, usize), &T)> + '_ {
+        Direction::ALL.into_iter().filter_map(move |d| {
+            let (nx, ny) = self.neighbour((x, y), d)?;
+            Some((d, (nx, ny), &self[(nx, ny)]))
+        })
+    }
+
+    /// Looks at the grid through `transform` without copying it.
+    pub fn view(&self, transform: Transform) -> GridView<'_, T> {
+        GridView {
+            grid: self,
+            transform,
+        }
+    }
+
+    pub fn transformed(&self, transform: Transform) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.view(transform).to_grid()
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            w: self.w,
+            h: self.h,
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("({x}, {y}) is outside a {}x{} grid", self.w, self.h))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        let (w, h) = (self.w, self.h);
+        self.get_mut(x, y)
+            .unwrap_or_else(|| panic!("({x}, {y}) is outside a {w}x{h} grid"))
+    }
+}
+
+/// A transformed, read only window onto a grid.
+#[derive(Clone, Copy)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    transform: Transform,
+}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn dimensions(&self) -> (usize, usize) {
+        self.transform.dimensions(self.grid.dimensions())
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&'a T> {
+        let (w, h) = self.dimensions();
+        if x >= w || y >= h {
+            return None;
+        }
+        let (sx, sy) = self.transform.source((x, y), self.grid.dimensions());
+        self.grid.get(sx, sy)
+    }
+
+    pub fn row(&self, y: usize) -> impl DoubleEndedIterator<Item = &'a T> + '_ {
+        let (w, _) = self.dimensions();
+        (0..w).filter_map(move |x| self.get(x, y))
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &'a T> + '_ {
+        let (_, h) = self.dimensions();
+        (0..h).filter_map(move |y| self.get(x, y))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &'a T> + '_> + '_ {
+        (0..self.dimensions().1).map(|y| self.row(y))
+    }
+
+    /// Every cell with its position in the view, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &'a T)> + '_ {
+        let (w, h) = self.dimensions();
+        (0..h)
+            .flat_map(move |y| (0..w).map(move |x| (x, y)))
+            .filter_map(|(x, y)| Some(((x, y), self.get(x, y)?)))
+    }
+
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let (w, h) = self.dimensions();
+        Grid {
+            cells: self.iter().map(|(_, item)| item.clone()).collect(),
+            w,
+            h,
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for GridView<'_, T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        let (w, h) = self.dimensions();
+        self.get(x, y)
+            .unwrap_or_else(|| panic!("({x}, {y}) is outside a {w}x{h} view"))
+    }
+}
+
+/// Parses one cell per character with the same conversion `BidiMap` uses. Skipped
+/// characters still take up a cell, holding `T::default()`.
+impl<T> FromStr for Grid<T>
+where
+    Parsed<T>: TryFrom<char>,
+    T: Default,
+{
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let mut cells = vec![];
+        let mut w = 0;
+        let mut h = 0;
+        for (y, line) in input::lines(s).enumerate() {
+            let width = line.chars().count();
+            if y == 0 {
+                w = width;
+            } else if width != w {
+                return Err(ParseError::new(s, line, format!("a row {w} wide")));
+            }
+            for (x, c) in line.chars().enumerate() {
+                let cell = Parsed::<T>::try_from(c).map_err(|_| {
+                    ParseError::at(line, x, "a known map character").rebase(s, line)
+                })?;
+                cells.push(match cell {
+                    Parsed::Item(item) => item,
+                    Parsed::Skip => T::default(),
+                });
+            }
+            h = y + 1;
+        }
+        Ok(Self { cells, w, h })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+    enum Cell {
+        #[default]
+        Empty,
+        Wall,
+    }
+
+    impl TryFrom<char> for Parsed<Cell> {
+        type Error = char;
+
+        fn try_from(value: char) -> Result<Self, Self::Error> {
+            match value {
+                '#' => Ok(Parsed::Item(Cell::Wall)),
+                '.' => Ok(Parsed::Skip),
+                c => Err(c),
+            }
+        }
+    }
+
+    #[test]
+    fn rows_columns_and_neighbours() {
+        let grid = Grid::<Cell>::from_str("#..\n.#.").unwrap();
+        assert_eq!((3, 2), grid.dimensions());
+        assert_eq!(Some(&Cell::Wall), grid.get(1, 1));
+        assert_eq!(None, grid.get(3, 0));
+        assert_eq!(
+            Some(&[Cell::Empty, Cell::Wall, Cell::Empty][..]),
+            grid.row(1)
+        );
+        let column = grid.column(0).unwrap().copied().collect::<Vec<_>>();
+        assert_eq!(vec![Cell::Wall, Cell::Empty], column);
+        let neighbours = grid
+            .neighbours((0, 0))
+            .map(|(d, pos, cell)| (d, pos, *cell))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (Direction::S, (0, 1), Cell::Empty),
+                (Direction::E, (1, 0), Cell::Empty)
+            ],
+            neighbours
+        );
+    }
+
+    #[test]
+    fn rejects_ragged_rows() {
+        let err = Grid::<Cell>::from_str("#..\n.#").unwrap_err();
+        assert_eq!(
+            (1, 0, "a row 3 wide"),
+            (err.line, err.column, err.expected.as_str())
+        );
+    }
+
+    #[test]
+    fn views_match_copies() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+        let rotated = grid.transformed(Transform::Rotate90);
+        assert_eq!(vec![vec![4, 1], vec![5, 2], vec![6, 3]], rows_of(&rotated));
+        let flipped = grid.transformed(Transform::FlipHorizontal);
+        assert_eq!(vec![vec![3, 2, 1], vec![6, 5, 4]], rows_of(&flipped));
+
+        let view = grid.view(Transform::Transpose);
+        assert_eq!((2, 3), view.dimensions());
+        assert_eq!(6, view[(1, 2)]);
+        assert_eq!(vec![&3, &6], view.row(2).collect::<Vec<_>>());
+        assert_eq!(grid, view.to_grid().transformed(Transform::Transpose));
+    }
+
+    fn rows_of(grid: &Grid<i32>) -> Vec<Vec<i32>> {
+        grid.rows().map(<[i32]>::to_vec).collect()
+    }
+}
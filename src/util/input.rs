@@ -0,0 +1,160 @@
+//! Helpers for the shapes puzzle input keeps coming in. Everything hands back slices of what
+//! it was given, so a `ParseError` built from them can still point at the right place, and
+//! Windows line endings are accepted wherever lines are split.
+
+use std::str::FromStr;
+
+use crate::error::ParseError;
+
+/// The lines of `raw`, without their line endings.
+pub fn lines(raw: &str) -> impl Iterator<Item = &str> + '_ {
+    raw.split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+}
+
+/// The groups of lines between blank lines. Runs of blank lines count as one separator and
+/// blank lines at either end are ignored.
+pub fn sections(raw: &str) -> impl Iterator<Item = &str> + '_ {
+    let mut rest = raw;
+    std::iter::from_fn(move || {
+        let mut start = None;
+        let mut end = 0;
+        let mut offset = 0;
+        for line in rest.split_inclusive('\n') {
+            let content = line.trim_end_matches(['\n', '\r']);
+            if content.trim().is_empty() {
+                if start.is_some() {
+                    break;
+                }
+            } else {
+                start.get_or_insert(offset);
+                end = offset + content.len();
+            }
+            offset += line.len();
+        }
+        let section = &rest[start?..end];
+        rest = &rest[offset..];
+        Some(section)
+    })
+}
+
+/// Parses each line of `raw` with `parse`, moving errors to their place in `raw`.
+pub fn parse_lines_with<T>(
+    raw: &str,
+    mut parse: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    lines(raw)
+        .map(|line| parse(line).map_err(|e| e.rebase(raw, line)))
+        .collect()
+}
+
+/// Parses each section of `raw`, moving errors to their place in `raw`.
+pub fn parse_sections<T>(raw: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    sections(raw)
+        .map(|section| T::from_str(section).map_err(|e| e.rebase(raw, section)))
+        .collect()
+}
+
+/// Every whitespace separated word of `line`, each of which must parse as a number.
+pub fn numbers<T: FromStr>(line: &str) -> Result<Vec<T>, ParseError> {
+    line.split_whitespace()
+        .map(|s| s.parse().map_err(|_| ParseError::new(line, s, "a number")))
+        .collect()
+}
+
+/// Every integer in `line`, whatever else surrounds them. A `-` straight before the digits
+/// makes them negative.
+pub fn integers<T: FromStr>(line: &str) -> Result<Vec<T>, ParseError> {
+    let bytes = line.as_bytes();
+    let mut found = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        let negative = bytes[i] == b'-' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit);
+        if !negative && !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        i += 1;
+        while bytes.get(i).is_some_and(u8::is_ascii_digit) {
+            i += 1;
+        }
+        let text = &line[start..i];
+        found.push(
+            text.parse()
+                .map_err(|_| ParseError::new(line, text, "a number that fits"))?,
+        );
+    }
+    Ok(found)
+}
+
+/// Splits a `key: values` line at its first colon, dropping the space after it.
+pub fn key_value(line: &str) -> Result<(&str, &str), ParseError> {
+    let (key, values) = line
+        .split_once(':')
+        .ok_or_else(|| ParseError::missing(line, "`:` after a label"))?;
+    Ok((key, values.trim_start()))
+}
+
+/// What follows `label` at the start of `line`, like the numbers after `Time:`.
+pub fn labelled<'a>(line: &'a str, label: &str) -> Result<&'a str, ParseError> {
+    line.strip_prefix(label)
+        .ok_or_else(|| ParseError::new(line, line, format!("a line starting with `{label}`")))
+}
+
+/// The two sides of `separator`, as in `a | b`, with the spaces around it dropped.
+pub fn split_pair<'a>(line: &'a str, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+    let (a, b) = line
+        .split_once(separator)
+        .ok_or_else(|| ParseError::missing(line, format!("`{separator}` between two parts")))?;
+    Ok((a.trim_end(), b.trim_start()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn splits_sections_and_lines() {
+        let raw = "\r\na\r\nb\r\n\r\n\r\nc\n  \nd\r\n";
+        assert_eq!(vec!["a\r\nb", "c", "d"], sections(raw).collect::<Vec<_>>());
+        assert_eq!(vec!["a", "b"], lines("a\r\nb").collect::<Vec<_>>());
+        assert_eq!(0, sections("\n\n").count());
+    }
+
+    #[test]
+    fn finds_numbers() {
+        assert_eq!(
+            Ok(vec![1, -20, 3, 4]),
+            integers::<i32>("x=1, y=-20..3 - 4x")
+        );
+        assert_eq!(Ok(vec![-4, 5]), integers::<i8>("a-4b5"));
+        assert_eq!(Ok(vec![7, 8]), numbers::<u32>(" 7  8 "));
+        let err = numbers::<u32>("7 x").unwrap_err();
+        assert_eq!(2, err.column);
+        let err = integers::<u8>("at 300").unwrap_err();
+        assert_eq!((3, 3), (err.column, err.width));
+    }
+
+    #[test]
+    fn keys_and_pairs_keep_positions() {
+        let raw = "Card 1: 41 48 | 83 86";
+        let (key, values) = key_value(raw).unwrap();
+        assert_eq!("Card 1", key);
+        let (winning, yours) = split_pair(values, "|").unwrap();
+        assert_eq!(("41 48", "83 86"), (winning, yours));
+        let err = ParseError::new(raw, &yours[3..], "x");
+        assert_eq!(19, err.column);
+        assert_eq!(Ok(" 7"), labelled("Time: 7", "Time:"));
+
+        let parsed = parse_lines_with("1\r\n2\r\nx", |line| {
+            line.parse::<u8>()
+                .map_err(|_| ParseError::new(line, line, "a number"))
+        });
+        let err = parsed.unwrap_err();
+        assert_eq!((2, 0, "x"), (err.line, err.column, err.text.as_str()));
+    }
+}
@@ -0,0 +1,366 @@
+use std::fmt::{self, Debug};
+
+/// The integers from `start` up to but not including `end`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub fn new(start: i64, end: i64) -> Self {
+        Self { start, end }
+    }
+
+    pub fn with_len(start: i64, len: i64) -> Self {
+        Self::new(start, start + len)
+    }
+
+    pub fn len(&self) -> i64 {
+        (self.end - self.start).max(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// The values in both, or `None` if they don't overlap.
+    pub fn intersection(&self, other: Interval) -> Option<Interval> {
+        let overlap = Interval::new(self.start.max(other.start), self.end.min(other.end));
+        (!overlap.is_empty()).then_some(overlap)
+    }
+
+    pub fn shift(&self, offset: i64) -> Interval {
+        Interval::new(self.start + offset, self.end + offset)
+    }
+
+    /// The values before `at` and the values from `at` on, either of which may be empty.
+    pub fn split_at(&self, at: i64) -> (Interval, Interval) {
+        let at = at.clamp(self.start, self.end.max(self.start));
+        (Interval::new(self.start, at), Interval::new(at, self.end))
+    }
+}
+
+impl Debug for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+/// A set of integers stored as sorted intervals that neither overlap nor touch.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        *self = self.union(&[interval].into_iter().collect());
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// How many integers are in the set.
+    pub fn len(&self) -> i64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let idx = self.intervals.partition_point(|i| i.end <= value);
+        self.intervals.get(idx).is_some_and(|i| i.contains(value))
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.intervals.first().map(|i| i.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.intervals.last().map(|i| i.end - 1)
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        self.intervals
+            .iter()
+            .chain(&other.intervals)
+            .copied()
+            .collect()
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut intervals = vec![];
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            intervals.extend(a.intersection(*b));
+            // whichever ends first can't overlap anything else in the other set
+            if a.end <= b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { intervals }
+    }
+
+    /// The values in this set but not in `other`.
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut intervals = vec![];
+        let mut others = other.intervals.iter().peekable();
+        for &interval in &self.intervals {
+            let mut rest = interval;
+            while let Some(cut) = others.next_if(|cut| cut.end <= rest.end) {
+                let (before, _) = rest.split_at(cut.start);
+                let (_, after) = rest.split_at(cut.end);
+                if !before.is_empty() {
+                    intervals.push(before);
+                }
+                rest = after;
+            }
+            // a cut running past this interval may still cover the start of the next one
+            if let Some(cut) = others.peek() {
+                rest = rest.split_at(cut.start).0;
+            }
+            if !rest.is_empty() {
+                intervals.push(rest);
+            }
+        }
+        Self { intervals }
+    }
+
+    /// The values below `at` and the values from `at` on.
+    pub fn split_at(&self, at: i64) -> (IntervalSet, IntervalSet) {
+        self.intervals.iter().map(|i| i.split_at(at)).unzip()
+    }
+
+    pub fn shift(&self, offset: i64) -> IntervalSet {
+        Self {
+            intervals: self.intervals.iter().map(|i| i.shift(offset)).collect(),
+        }
+    }
+}
+
+/// Sorts and merges, dropping empty intervals.
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut sorted: Vec<_> = iter.into_iter().filter(|i| !i.is_empty()).collect();
+        sorted.sort();
+        let mut intervals: Vec<Interval> = vec![];
+        for interval in sorted {
+            match intervals.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => intervals.push(interval),
+            }
+        }
+        Self { intervals }
+    }
+}
+
+impl Extend<Interval> for IntervalSet {
+    fn extend<I: IntoIterator<Item = Interval>>(&mut self, iter: I) {
+        *self = self.intervals.iter().copied().chain(iter).collect();
+    }
+}
+
+/// A function on the integers that adds a fixed offset to each value in a piece's domain
+/// and leaves every value outside all pieces alone.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct LinearMap {
+    /// Sorted by domain, which never overlap.
+    pieces: Vec<(Interval, i64)>,
+}
+
+impl LinearMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maps `domain` by `offset`. Values already covered by an earlier piece keep their
+    /// mapping.
+    pub fn insert(&mut self, domain: Interval, offset: i64) {
+        let uncovered = [domain]
+            .into_iter()
+            .collect::<IntervalSet>()
+            .difference(&self.domain());
+        self.pieces
+            .extend(uncovered.intervals().iter().map(|&i| (i, offset)));
+        self.pieces.sort();
+    }
+
+    /// Each piece's domain and offset.
+    pub fn pieces(&self) -> &[(Interval, i64)] {
+        &self.pieces
+    }
+
+    /// The values that get moved by some piece.
+    pub fn domain(&self) -> IntervalSet {
+        self.pieces.iter().map(|(domain, _)| *domain).collect()
+    }
+
+    pub fn apply(&self, value: i64) -> i64 {
+        let idx = self
+            .pieces
+            .partition_point(|(domain, _)| domain.end <= value);
+        match self.pieces.get(idx) {
+            Some((domain, offset)) if domain.contains(value) => value + offset,
+            _ => value,
+        }
+    }
+
+    /// Where every value in `set` ends up.
+    pub fn apply_set(&self, set: &IntervalSet) -> IntervalSet {
+        let moved = self.pieces.iter().flat_map(|&(domain, offset)| {
+            set.intervals
+                .iter()
+                .filter_map(move |i| i.intersection(domain))
+                .map(move |i| i.shift(offset))
+        });
+        set.difference(&self.domain())
+            .intervals
+            .into_iter()
+            .chain(moved)
+            .collect()
+    }
+
+    /// The map that applies this one and then `next`.
+    pub fn then(&self, next: &LinearMap) -> LinearMap {
+        let mut pieces = vec![];
+        for &(domain, offset) in &self.pieces {
+            // split where this piece lands by the pieces of `next` it lands on
+            let landed = [domain.shift(offset)].into_iter().collect::<IntervalSet>();
+            for &(next_domain, next_offset) in &next.pieces {
+                if let Some(overlap) = domain.shift(offset).intersection(next_domain) {
+                    pieces.push((overlap.shift(-offset), offset + next_offset));
+                }
+            }
+            let untouched = landed.difference(&next.domain());
+            pieces.extend(
+                untouched
+                    .intervals
+                    .iter()
+                    .map(|i| (i.shift(-offset), offset)),
+            );
+        }
+        // values this map leaves alone go straight into `next`
+        let unmoved = next.domain().difference(&self.domain());
+        for &(next_domain, next_offset) in &next.pieces {
+            let set = [next_domain].into_iter().collect::<IntervalSet>();
+            let reached = set.intersection(&unmoved);
+            pieces.extend(reached.intervals.iter().map(|&i| (i, next_offset)));
+        }
+        pieces.retain(|(_, offset)| *offset != 0);
+        pieces.sort();
+        LinearMap { pieces }
+    }
+
+    /// The map undoing this one, or `None` if two values map to the same place.
+    pub fn inverse(&self) -> Option<LinearMap> {
+        let images: IntervalSet = self
+            .pieces
+            .iter()
+            .map(|(domain, offset)| domain.shift(*offset))
+            .collect();
+        // anything landing outside the domain would collide with a value left where it
+        // was, and if the images covered the domain while overlapping they'd come up short
+        if images != self.domain() {
+            return None;
+        }
+        let mut pieces: Vec<_> = self
+            .pieces
+            .iter()
+            .map(|(domain, offset)| (domain.shift(*offset), -offset))
+            .collect();
+        pieces.sort();
+        Some(LinearMap { pieces })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+        intervals
+            .iter()
+            .map(|&(s, e)| Interval::new(s, e))
+            .collect()
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[(0, 5), (3, 8), (8, 10), (20, 30), (40, 40)]);
+        assert_eq!(set(&[(0, 10), (20, 30)]).intervals(), a.intervals());
+        assert_eq!(20, a.len());
+        assert!(a.contains(9) && !a.contains(10) && a.contains(20));
+        assert_eq!((Some(0), Some(29)), (a.min(), a.max()));
+
+        let b = set(&[(5, 25), (28, 35)]);
+        assert_eq!(set(&[(0, 35)]), a.union(&b));
+        assert_eq!(set(&[(5, 10), (20, 25), (28, 30)]), a.intersection(&b));
+        assert_eq!(set(&[(0, 5), (25, 28)]), a.difference(&b));
+        assert_eq!(set(&[(10, 20), (30, 35)]), b.difference(&a));
+        assert_eq!(
+            (set(&[(0, 10), (20, 22)]), set(&[(22, 30)])),
+            a.split_at(22)
+        );
+    }
+
+    #[test]
+    fn maps_compose_and_invert() {
+        // the seed-to-soil and soil-to-fertilizer maps from the day 5 example
+        let mut soil = LinearMap::new();
+        soil.insert(Interval::with_len(98, 2), 50 - 98);
+        soil.insert(Interval::with_len(50, 48), 52 - 50);
+        let mut fertilizer = LinearMap::new();
+        fertilizer.insert(Interval::with_len(15, 37), -15);
+        fertilizer.insert(Interval::with_len(52, 2), 37 - 52);
+        fertilizer.insert(Interval::with_len(0, 15), 39);
+
+        assert_eq!(81, soil.apply(79));
+        assert_eq!(14, soil.apply(14));
+        let both = soil.then(&fertilizer);
+        for seed in 0..120 {
+            assert_eq!(
+                fertilizer.apply(soil.apply(seed)),
+                both.apply(seed),
+                "{seed}"
+            );
+        }
+
+        let seeds = set(&[(79, 93), (55, 68)]);
+        let expected: IntervalSet = (55..68)
+            .chain(79..93)
+            .map(|seed| Interval::with_len(both.apply(seed), 1))
+            .collect();
+        assert_eq!(expected, both.apply_set(&seeds));
+
+        let inverse = soil.inverse().expect("soil is a bijection");
+        for seed in 0..120 {
+            assert_eq!(seed, inverse.apply(soil.apply(seed)));
+        }
+        let mut squash = LinearMap::new();
+        squash.insert(Interval::new(10, 20), -5);
+        assert_eq!(None, squash.inverse());
+    }
+
+    #[test]
+    fn earlier_pieces_win() {
+        let mut map = LinearMap::new();
+        map.insert(Interval::new(0, 10), 100);
+        map.insert(Interval::new(5, 15), 200);
+        assert_eq!((109, 210), (map.apply(9), map.apply(10)));
+        assert_eq!(2, map.pieces().len());
+    }
+}
@@ -0,0 +1,143 @@
+//! Modular arithmetic for lining cycles up. Intermediate products are worked out in `i128`,
+//! and anything that doesn't fit back into an `i64` comes out as `None` rather than a panic.
+
+/// `(g, x, y)` where `g` is the non-negative gcd of `a` and `b` and `a * x + b * y == g`.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..m` with `a * x ≡ 1 (mod m)`, if `m` is positive and coprime to `a`.
+pub fn mod_inverse(a: i64, m: i64) -> Option<i64> {
+    if m <= 0 {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    (g == 1).then(|| x.rem_euclid(m))
+}
+
+/// The numbers `n` with `n ≡ residue (mod modulus)`. The residue is kept in `0..modulus`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Congruence {
+    pub residue: i64,
+    pub modulus: i64,
+}
+
+impl Congruence {
+    /// Every integer.
+    pub const ALL: Congruence = Congruence {
+        residue: 0,
+        modulus: 1,
+    };
+
+    /// `None` unless `modulus` is positive.
+    pub fn new(residue: i64, modulus: i64) -> Option<Self> {
+        (modulus > 0).then(|| Self {
+            residue: residue.rem_euclid(modulus),
+            modulus,
+        })
+    }
+
+    pub fn contains(&self, n: i64) -> bool {
+        n.rem_euclid(self.modulus) == self.residue
+    }
+
+    /// The numbers satisfying both congruences, or `None` if there aren't any or their
+    /// modulus would overflow. The moduli don't need to be coprime.
+    pub fn merge(&self, other: &Congruence) -> Option<Congruence> {
+        let (g, x, _) = extended_gcd(self.modulus, other.modulus);
+        let difference = other.residue - self.residue;
+        if difference % g != 0 {
+            return None;
+        }
+        // self.residue + self.modulus * k satisfies other when
+        // k ≡ (difference / g) * x (mod other.modulus / g)
+        let step = (other.modulus / g) as i128;
+        let k = ((difference / g) as i128 * x as i128).rem_euclid(step);
+        let modulus = self.modulus as i128 * step;
+        let residue = (self.residue as i128 + self.modulus as i128 * k).rem_euclid(modulus);
+        Congruence::new(residue.try_into().ok()?, modulus.try_into().ok()?)
+    }
+
+    /// The smallest number at least `min` that satisfies the congruence, if it fits.
+    pub fn first_at_least(&self, min: i64) -> Option<i64> {
+        let offset = (self.residue as i128 - min as i128).rem_euclid(self.modulus as i128);
+        min.checked_add(offset as i64)
+    }
+}
+
+/// The generalised Chinese remainder theorem: every number satisfying all of `congruences`,
+/// as one congruence modulo the lcm of their moduli. `None` if they conflict.
+pub fn crt(congruences: impl IntoIterator<Item = Congruence>) -> Option<Congruence> {
+    congruences
+        .into_iter()
+        .try_fold(Congruence::ALL, |acc, c| acc.merge(&c))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn congruence(residue: i64, modulus: i64) -> Congruence {
+        Congruence::new(residue, modulus).unwrap()
+    }
+
+    #[test]
+    fn gcd_and_inverse() {
+        assert_eq!((6, -2, 3), extended_gcd(240, 162));
+        let (g, x, y) = extended_gcd(-12, 18);
+        assert_eq!((6, 6), (g, -12 * x + 18 * y));
+        assert_eq!((5, 1, 0), extended_gcd(5, 0));
+        assert_eq!(Some(4), mod_inverse(3, 11));
+        assert_eq!(Some(7), mod_inverse(-3, 11));
+        assert_eq!(None, mod_inverse(4, 10));
+        assert_eq!(None, mod_inverse(3, 0));
+        assert_eq!(None, mod_inverse(3, -5));
+    }
+
+    #[test]
+    fn chinese_remainders() {
+        let coprime = [(2, 3), (3, 5), (2, 7)].map(|(r, m)| congruence(r, m));
+        assert_eq!(Some(congruence(23, 105)), crt(coprime));
+        // 4 and 6 share a factor, so the answer repeats every 12 rather than 24
+        let shared = [congruence(3, 4), congruence(5, 6)];
+        assert_eq!(Some(congruence(11, 12)), crt(shared));
+        let conflicting = [congruence(1, 4), congruence(2, 6)];
+        assert_eq!(None, crt(conflicting));
+        assert_eq!(Some(Congruence::ALL), crt([]));
+    }
+
+    #[test]
+    fn large_moduli_and_offsets() {
+        let big = 1_000_000_007;
+        let merged = crt([congruence(5, big), congruence(-1, big - 2)]).unwrap();
+        assert_eq!(big * (big - 2), merged.modulus);
+        assert_eq!(5, merged.residue % big);
+        assert_eq!(big - 3, merged.residue % (big - 2));
+        assert!(merged.contains(merged.residue - merged.modulus));
+        assert_eq!(Some(23), congruence(23, 105).first_at_least(0));
+        assert_eq!(Some(128), congruence(23, 105).first_at_least(24));
+        assert_eq!(Some(-82), congruence(23, 105).first_at_least(-100));
+    }
+
+    #[test]
+    fn overflow_and_bad_moduli_give_none() {
+        assert_eq!(None, Congruence::new(1, 0));
+        assert_eq!(None, Congruence::new(1, -4));
+        let huge = [congruence(0, i64::MAX), congruence(1, i64::MAX - 1)];
+        assert_eq!(None, crt(huge));
+        assert_eq!(None, congruence(1, i64::MAX).first_at_least(3));
+    }
+}
@@ -0,0 +1,95 @@
+use std::{collections::HashMap, hash::Hash};
+
+/// How well a `Memo` has been doing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MemoStats {
+    pub entries: usize,
+    pub hits: usize,
+    pub misses: usize,
+}
+
+/// A cache for a recursive function, keyed by exact values so different arguments can never
+/// share an answer. Make one per solve and drop it afterwards, rather than letting results
+/// pile up across inputs.
+///
+/// Recursive calls go through the memo handed to `compute`:
+///
+/// ```
+/// use aoc2023::util::memo::Memo;
+///
+/// fn fib(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+///     memo.get_or_compute(n, |memo| if n < 2 { n } else { fib(memo, n - 1) + fib(memo, n - 2) })
+/// }
+///
+/// assert_eq!(12586269025, fib(&mut Memo::new(), 50));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    hits: usize,
+    misses: usize,
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self {
+            cache: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// The cached value for `key`, or the one `compute` works out, which is then cached.
+    pub fn get_or_compute(&mut self, key: K, compute: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            self.hits += 1;
+            return value.clone();
+        }
+        self.misses += 1;
+        let value = compute(self);
+        self.cache.insert(key, value.clone());
+        value
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        MemoStats {
+            entries: self.cache.len(),
+            hits: self.hits,
+            misses: self.misses,
+        }
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn paths(memo: &mut Memo<(usize, usize), u64>, x: usize, y: usize) -> u64 {
+        memo.get_or_compute((x, y), |memo| {
+            if x == 0 || y == 0 {
+                1
+            } else {
+                paths(memo, x - 1, y) + paths(memo, x, y - 1)
+            }
+        })
+    }
+
+    #[test]
+    fn counts_hits_and_misses() {
+        let mut memo = Memo::new();
+        assert_eq!(184756, paths(&mut memo, 10, 10));
+        let stats = memo.stats();
+        // every point but the unreachable (0, 0) is computed once
+        assert_eq!(11 * 11 - 1, stats.entries);
+        assert_eq!(stats.entries, stats.misses);
+        assert_eq!(2 * 10 * 10 - stats.entries + 1, stats.hits);
+        assert_eq!(184756, paths(&mut memo, 10, 10));
+        assert_eq!(stats.hits + 1, memo.stats().hits);
+    }
+}
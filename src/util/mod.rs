@@ -0,0 +1,14 @@
+pub mod bidimap;
+pub mod coord;
+pub mod cycle;
+pub mod direction;
+pub mod flood;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod math;
+pub mod memo;
+pub mod render;
+pub mod search;
+pub mod transform;
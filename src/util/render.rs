@@ -0,0 +1,162 @@
+use std::{
+    collections::HashSet,
+    fmt::{self, Display},
+};
+
+use super::{bidimap::BidiMap, grid::Grid};
+
+/// Terminal colours for `Render::ansi`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Colour {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Colour {
+    fn code(self) -> u8 {
+        match self {
+            Colour::Red => 31,
+            Colour::Green => 32,
+            Colour::Yellow => 33,
+            Colour::Blue => 34,
+            Colour::Magenta => 35,
+            Colour::Cyan => 36,
+        }
+    }
+}
+
+struct Layer<'a> {
+    cell: Box<dyn Fn((usize, usize)) -> Option<char> + 'a>,
+    colour: Option<Colour>,
+}
+
+/// Draws a map as text, one line per row. Each cell shows the character of the last layer
+/// with something to say about it, or the background if none do, so later layers sit on top
+/// of earlier ones.
+///
+/// Printing with `{}` gives plain text fit for comparing in tests; colours are only added
+/// once `ansi` is turned on.
+pub struct Render<'a> {
+    dimensions: (usize, usize),
+    background: char,
+    layers: Vec<Layer<'a>>,
+    ansi: bool,
+}
+
+impl<'a> Render<'a> {
+    pub fn new(dimensions: (usize, usize)) -> Self {
+        Self {
+            dimensions,
+            background: '.',
+            layers: vec![],
+            ansi: false,
+        }
+    }
+
+    pub fn background(mut self, c: char) -> Self {
+        self.background = c;
+        self
+    }
+
+    /// Adds a layer drawing `cell`'s character wherever it gives one.
+    pub fn layer(mut self, cell: impl Fn((usize, usize)) -> Option<char> + 'a) -> Self {
+        self.layers.push(Layer {
+            cell: Box::new(cell),
+            colour: None,
+        });
+        self
+    }
+
+    /// Adds a layer with a character for every item in `map`.
+    pub fn map<T>(self, map: &'a BidiMap<T>, c: impl Fn(&T) -> char + 'a) -> Self {
+        self.layer(move |(x, y)| map.get(x, y).map(&c))
+    }
+
+    /// Adds a layer drawing the cells of `grid` that `c` gives a character for.
+    pub fn grid<T>(self, grid: &'a Grid<T>, c: impl Fn(&T) -> Option<char> + 'a) -> Self {
+        self.layer(move |(x, y)| grid.get(x, y).and_then(&c))
+    }
+
+    /// Adds a layer marking every one of `cells` with `c`.
+    pub fn cells(self, cells: impl IntoIterator<Item = (usize, usize)>, c: char) -> Self {
+        let cells: HashSet<_> = cells.into_iter().collect();
+        self.layer(move |pos| cells.contains(&pos).then_some(c))
+    }
+
+    /// Colours the most recently added layer.
+    pub fn colour(mut self, colour: Colour) -> Self {
+        if let Some(layer) = self.layers.last_mut() {
+            layer.colour = Some(colour);
+        }
+        self
+    }
+
+    /// Whether to wrap coloured layers in ANSI escape codes.
+    pub fn ansi(mut self, ansi: bool) -> Self {
+        self.ansi = ansi;
+        self
+    }
+}
+
+impl Display for Render<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (w, h) = self.dimensions;
+        for y in 0..h {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for x in 0..w {
+                let top = self
+                    .layers
+                    .iter()
+                    .rev()
+                    .find_map(|layer| (layer.cell)((x, y)).map(|c| (c, layer.colour)));
+                match top {
+                    Some((c, Some(colour))) if self.ansi => {
+                        write!(f, "\x1b[{}m{c}\x1b[0m", colour.code())?
+                    }
+                    Some((c, _)) => write!(f, "{c}")?,
+                    None => write!(f, "{}", self.background)?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn later_layers_win() {
+        let mut rocks = BidiMap::with_dimensions(3, 2);
+        rocks.insert(0, 0, 'O');
+        rocks.insert(2, 1, '#');
+        let render = Render::new(rocks.dimensions())
+            .map(&rocks, |c| *c)
+            .cells([(0, 0), (1, 0)], '*')
+            .background(' ');
+        assert_eq!("** \n  #", render.to_string());
+    }
+
+    #[test]
+    fn colours_only_with_ansi() {
+        let grid = Grid::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        let odd = |n: &i32| (n % 2 == 1).then_some('o');
+        let render = || {
+            Render::new(grid.dimensions())
+                .grid(&grid, odd)
+                .colour(Colour::Red)
+        };
+        assert_eq!("o.\no.", render().to_string());
+        assert_eq!(
+            "\x1b[31mo\x1b[0m.\n\x1b[31mo\x1b[0m.",
+            render().ansi(true).to_string()
+        );
+    }
+}
@@ -0,0 +1,338 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+use super::direction::{Direction, Turn};
+
+/// Something moving across a map: where it is, which way it faces and whatever else the
+/// puzzle needs to tell two visits apart, like how far it has gone in a straight line.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct Walker<X = ()> {
+    pub pos: (usize, usize),
+    pub direction: Direction,
+    pub extra: X,
+}
+
+impl Walker {
+    pub fn new(pos: (usize, usize), direction: Direction) -> Self {
+        Self {
+            pos,
+            direction,
+            extra: (),
+        }
+    }
+}
+
+impl<X> Walker<X> {
+    /// One step forward, or `None` if that leaves a `w` by `h` map.
+    pub fn ahead(self, dimensions: (usize, usize)) -> Option<Self> {
+        let pos = self.direction.move_fowrard(self.pos, dimensions)?;
+        Some(Self { pos, ..self })
+    }
+
+    pub fn turned(self, turn: Turn) -> Self {
+        Self {
+            direction: self.direction.turn(turn),
+            ..self
+        }
+    }
+
+    pub fn facing(self, direction: Direction) -> Self {
+        Self { direction, ..self }
+    }
+
+    pub fn with<Y>(self, extra: Y) -> Walker<Y> {
+        Walker {
+            pos: self.pos,
+            direction: self.direction,
+            extra,
+        }
+    }
+}
+
+/// Everything a search found: the cost of reaching each visited state, how it got there,
+/// and the goal it stopped at, if any.
+pub struct Search<S> {
+    costs: HashMap<S, usize>,
+    predecessors: HashMap<S, S>,
+    goal: Option<S>,
+}
+
+impl<S: Clone + Eq + Hash> Search<S> {
+    fn new() -> Self {
+        Self {
+            costs: HashMap::new(),
+            predecessors: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    /// The first state that satisfied the goal.
+    pub fn goal(&self) -> Option<&S> {
+        self.goal.as_ref()
+    }
+
+    /// The cost of the goal, if one was reached.
+    pub fn goal_cost(&self) -> Option<usize> {
+        self.goal.as_ref().and_then(|goal| self.cost(goal))
+    }
+
+    /// The cheapest cost to `state` found, which is exact for every state that was settled
+    /// before the search stopped.
+    pub fn cost(&self, state: &S) -> Option<usize> {
+        self.costs.get(state).copied()
+    }
+
+    pub fn contains(&self, state: &S) -> bool {
+        self.costs.contains_key(state)
+    }
+
+    pub fn len(&self) -> usize {
+        self.costs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.costs.is_empty()
+    }
+
+    /// Every state the search reached, in no particular order.
+    pub fn visited(&self) -> impl Iterator<Item = &S> + '_ {
+        self.costs.keys()
+    }
+
+    /// The states from a start up to and including `target`.
+    pub fn path_to(&self, target: &S) -> Option<Vec<S>> {
+        if !self.contains(target) {
+            return None;
+        }
+        let mut path = vec![target.clone()];
+        let mut current = target;
+        while let Some(previous) = self.predecessors.get(current) {
+            path.push(previous.clone());
+            current = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// The path to the goal, if one was reached.
+    pub fn path(&self) -> Option<Vec<S>> {
+        self.goal.as_ref().and_then(|goal| self.path_to(goal))
+    }
+}
+
+/// Breadth first search where every move costs 1. Stops at the first state `goal` accepts;
+/// pass `|_| false` to explore everything reachable.
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut next: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if search.costs.insert(start.clone(), 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+    while let Some(state) = queue.pop_front() {
+        if goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+        let cost = search.costs[&state] + 1;
+        for neighbour in next(&state) {
+            if let Entry::Vacant(entry) = search.costs.entry(neighbour.clone()) {
+                entry.insert(cost);
+                search.predecessors.insert(neighbour.clone(), state.clone());
+                queue.push_back(neighbour);
+            }
+        }
+    }
+    search
+}
+
+/// Every state reachable from `starts`, without the bookkeeping `bfs` does for costs and
+/// paths.
+pub fn reachable<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut next: impl FnMut(&S) -> I,
+) -> HashSet<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = HashSet::new();
+    let mut stack = Vec::new();
+    for start in starts {
+        if visited.insert(start.clone()) {
+            stack.push(start);
+        }
+    }
+    while let Some(state) = stack.pop() {
+        for neighbour in next(&state) {
+            if visited.insert(neighbour.clone()) {
+                stack.push(neighbour);
+            }
+        }
+    }
+    visited
+}
+
+/// Cheapest first search where `next` gives each neighbour with the cost of moving there.
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    next: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    astar(starts, next, |_| 0, goal)
+}
+
+/// Dijkstra guided by `heuristic`, an estimate of the remaining cost that must never be
+/// more than the real one or the result may not be the cheapest.
+pub fn astar<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut next: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> usize,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut search = Search::new();
+    // states live in `seen` so the heap doesn't need them to be Ord
+    let mut seen = Vec::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        if search.costs.insert(start.clone(), 0).is_none() {
+            queue.push(Reverse((heuristic(&start), 0, seen.len())));
+            seen.push(start);
+        }
+    }
+    while let Some(Reverse((_, cost, idx))) = queue.pop() {
+        let state = seen[idx].clone();
+        if search.costs[&state] < cost {
+            // a cheaper way here was queued later
+            continue;
+        }
+        if goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+        for (neighbour, step) in next(&state) {
+            let cost = cost + step;
+            match search.costs.entry(neighbour.clone()) {
+                Entry::Occupied(entry) if *entry.get() <= cost => continue,
+                Entry::Occupied(mut entry) => {
+                    entry.insert(cost);
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(cost);
+                }
+            }
+            search.predecessors.insert(neighbour.clone(), state.clone());
+            queue.push(Reverse((cost + heuristic(&neighbour), cost, seen.len())));
+            seen.push(neighbour);
+        }
+    }
+    search
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::util::{bidimap::BidiMap, grid::Grid};
+
+    #[test]
+    fn bfs_finds_shortest_path_around_walls() {
+        let mut walls = BidiMap::with_dimensions(3, 3);
+        for (x, y) in [(2, 0), (1, 1), (2, 1)] {
+            walls.insert(x, y, ());
+        }
+        let (w, h) = walls.dimensions();
+        let open = |(x, y): &(usize, usize)| walls.get(*x, *y).is_none();
+        let search = bfs(
+            [(1, 0)],
+            |&pos| {
+                Direction::ALL
+                    .into_iter()
+                    .filter_map(move |d| d.move_fowrard(pos, (w, h)))
+                    .filter(open)
+                    .collect::<Vec<_>>()
+            },
+            |&pos| pos == (2, 2),
+        );
+        assert_eq!(Some(5), search.goal_cost());
+        assert_eq!(
+            Some(vec![(1, 0), (0, 0), (0, 1), (0, 2), (1, 2), (2, 2)]),
+            search.path()
+        );
+        assert_eq!(Some(1), search.cost(&(0, 0)));
+        assert_eq!(None, search.path_to(&(1, 1)));
+        let all = reachable([(1, 0)], |&pos| {
+            Direction::ALL
+                .into_iter()
+                .filter_map(move |d| d.move_fowrard(pos, (w, h)))
+                .filter(open)
+                .collect::<Vec<_>>()
+        });
+        assert_eq!(6, all.len());
+        assert_eq!(all, search.visited().copied().collect());
+    }
+
+    fn crucible(heat: &Grid<usize>, from: &Walker<usize>) -> Vec<(Walker<usize>, usize)> {
+        [Turn::Left, Turn::Right]
+            .map(|turn| from.turned(turn).with(0))
+            .into_iter()
+            .chain((from.extra < 3).then_some(*from))
+            .filter_map(|walker| walker.ahead(heat.dimensions()))
+            .map(|walker| {
+                let run = walker.extra + 1;
+                (walker.with(run), heat[walker.pos])
+            })
+            .collect()
+    }
+
+    #[test]
+    fn dijkstra_and_astar_with_run_lengths() {
+        let heat = Grid::from_rows(
+            "2413432311323\n3215453535623\n3255245654254\n3446585845452\n4546657867536\n\
+             1438598798454\n4457876987766\n3637877979653\n4654967986887\n4564679986453\n\
+             1224686865563\n2546548887735\n4322674655533"
+                .lines()
+                .map(|line| line.bytes().map(|b| (b - b'0') as usize).collect())
+                .collect(),
+        )
+        .unwrap();
+        let (w, h) = heat.dimensions();
+        let starts = [Direction::E, Direction::S].map(|d| Walker::new((0, 0), d).with(0));
+        let end = |walker: &Walker<usize>| walker.pos == (w - 1, h - 1);
+
+        let plain = dijkstra(starts, |walker| crucible(&heat, walker), end);
+        assert_eq!(Some(102), plain.goal_cost());
+        let path = plain.path().unwrap();
+        let total: usize = path.iter().skip(1).map(|walker| heat[walker.pos]).sum();
+        assert_eq!(102, total);
+        assert!(path.iter().all(|walker| walker.extra <= 3));
+
+        let guided = astar(
+            starts,
+            |walker| crucible(&heat, walker),
+            |walker| (w - 1 - walker.pos.0) + (h - 1 - walker.pos.1),
+            end,
+        );
+        assert_eq!(Some(102), guided.goal_cost());
+        assert!(guided.len() <= plain.len());
+    }
+}
@@ -0,0 +1,131 @@
+use super::direction::Direction;
+
+/// One of the eight ways to turn or mirror a rectangular map onto itself. Rotations are
+/// clockwise.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Transform {
+    Identity,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+    /// Mirrors left to right.
+    FlipHorizontal,
+    /// Mirrors top to bottom.
+    FlipVertical,
+    /// Swaps x and y, mirroring along the leading diagonal.
+    Transpose,
+    /// Mirrors along the other diagonal.
+    AntiTranspose,
+}
+
+impl Transform {
+    /// The rotation that turns `direction` into north, so direction dependent code only
+    /// needs writing for one heading.
+    pub fn facing_north(direction: Direction) -> Self {
+        match direction {
+            Direction::N => Transform::Identity,
+            Direction::E => Transform::Rotate270,
+            Direction::S => Transform::Rotate180,
+            Direction::W => Transform::Rotate90,
+        }
+    }
+
+    pub fn inverse(self) -> Self {
+        match self {
+            Transform::Rotate90 => Transform::Rotate270,
+            Transform::Rotate270 => Transform::Rotate90,
+            t => t,
+        }
+    }
+
+    /// Whether width and height trade places.
+    pub fn swaps_axes(self) -> bool {
+        matches!(
+            self,
+            Transform::Rotate90
+                | Transform::Rotate270
+                | Transform::Transpose
+                | Transform::AntiTranspose
+        )
+    }
+
+    /// The size of a `w` by `h` map after the transform.
+    pub fn dimensions(self, (w, h): (usize, usize)) -> (usize, usize) {
+        if self.swaps_axes() {
+            (h, w)
+        } else {
+            (w, h)
+        }
+    }
+
+    /// Where `(x, y)` of a `w` by `h` map ends up.
+    pub fn apply(self, (x, y): (usize, usize), (w, h): (usize, usize)) -> (usize, usize) {
+        match self {
+            Transform::Identity => (x, y),
+            Transform::Rotate90 => (h - 1 - y, x),
+            Transform::Rotate180 => (w - 1 - x, h - 1 - y),
+            Transform::Rotate270 => (y, w - 1 - x),
+            Transform::FlipHorizontal => (w - 1 - x, y),
+            Transform::FlipVertical => (x, h - 1 - y),
+            Transform::Transpose => (y, x),
+            Transform::AntiTranspose => (h - 1 - y, w - 1 - x),
+        }
+    }
+
+    /// Where `(x, y)` of the transformed map came from in the original `w` by `h` one.
+    pub fn source(self, (x, y): (usize, usize), (w, h): (usize, usize)) -> (usize, usize) {
+        self.inverse().apply((x, y), self.dimensions((w, h)))
+    }
+
+    /// Where a heading on the original map points after the transform.
+    pub fn direction(self, direction: Direction) -> Direction {
+        let (dx, dy) = direction.offset();
+        // push a unit step through the transform on a 3x3 map centred on (1, 1)
+        let (x, y) = self.apply(((1 + dx) as usize, (1 + dy) as usize), (3, 3));
+        let offset = (x as i32 - 1, y as i32 - 1);
+        Direction::ALL
+            .into_iter()
+            .find(|d| d.offset() == offset)
+            .unwrap_or(direction)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ALL: [Transform; 8] = [
+        Transform::Identity,
+        Transform::Rotate90,
+        Transform::Rotate180,
+        Transform::Rotate270,
+        Transform::FlipHorizontal,
+        Transform::FlipVertical,
+        Transform::Transpose,
+        Transform::AntiTranspose,
+    ];
+
+    #[test]
+    fn source_undoes_apply() {
+        let size = (4, 3);
+        for t in ALL {
+            for (x, y) in [(0, 0), (3, 0), (1, 2), (3, 2)] {
+                let moved = t.apply((x, y), size);
+                let (w, h) = t.dimensions(size);
+                assert!(
+                    moved.0 < w && moved.1 < h,
+                    "{t:?} moved ({x}, {y}) off the map"
+                );
+                assert_eq!((x, y), t.source(moved, size), "{t:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn faces_north() {
+        for d in Direction::ALL {
+            assert_eq!(Direction::N, Transform::facing_north(d).direction(d));
+        }
+        assert_eq!(Direction::E, Transform::Rotate90.direction(Direction::N));
+    }
+}
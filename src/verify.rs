@@ -0,0 +1,183 @@
+use std::{
+    fmt::{self, Display},
+    fs::read_to_string,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{bail, Context, Result};
+use toml::{Table, Value};
+
+use crate::{error::ErrorKind, find_day, source, Answer};
+
+/// A stored answer for one part of a day against a particular input.
+#[derive(Debug, Clone)]
+pub struct Expected {
+    pub day: u8,
+    pub input: PathBuf,
+    pub part: u8,
+    pub answer: String,
+}
+
+/// Reads an answers file shaped like
+///
+/// ```toml
+/// [day01."inputs/01.txt"]
+/// part1 = 55834
+/// part2 = 53221
+/// ```
+///
+/// Input paths are relative to the answers file.
+pub fn load_answers(path: &Path) -> Result<Vec<Expected>> {
+    let raw = read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+    let table = raw
+        .parse::<Table>()
+        .with_context(|| format!("failed to parse {}", path.display()))?;
+    let base = path.parent().unwrap_or(Path::new("."));
+
+    let mut expected = vec![];
+    for (day_key, inputs) in &table {
+        let day = day_key
+            .strip_prefix("day")
+            .and_then(|d| d.parse::<u8>().ok())
+            .with_context(|| format!("expected a key like day01, got {day_key}"))?;
+        let inputs = inputs
+            .as_table()
+            .with_context(|| format!("{day_key} should be a table of inputs"))?;
+        for (input, parts) in inputs {
+            let parts = parts
+                .as_table()
+                .with_context(|| format!("{day_key}.{input} should be a table of parts"))?;
+            for (part_key, answer) in parts {
+                let part = match part_key.as_str() {
+                    "part1" => 1,
+                    "part2" => 2,
+                    p => bail!("unknown part {p} in {day_key}.{input}"),
+                };
+                let answer = match answer {
+                    Value::Integer(n) => n.to_string(),
+                    Value::String(s) => s.clone(),
+                    v => {
+                        bail!("{day_key}.{input}.{part_key} should be a number or string, got {v}")
+                    }
+                };
+                expected.push(Expected {
+                    day,
+                    input: base.join(input),
+                    part,
+                    answer,
+                });
+            }
+        }
+    }
+    expected.sort_by(|a, b| (a.day, &a.input, a.part).cmp(&(b.day, &b.input, b.part)));
+    Ok(expected)
+}
+
+#[derive(Debug)]
+pub enum Outcome {
+    Pass,
+    Mismatch { expected: String, actual: Answer },
+    Fail(String),
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Pass => write!(f, "pass"),
+            Outcome::Mismatch { expected, actual } => {
+                write!(f, "mismatch (expected {expected}, got {actual})")
+            }
+            Outcome::Fail(e) => write!(f, "fail ({e})"),
+        }
+    }
+}
+
+pub struct Check {
+    pub expected: Expected,
+    pub outcome: Outcome,
+}
+
+/// Runs every stored answer, parsing each input once for all of its parts.
+pub fn verify(expected: &[Expected]) -> Vec<Check> {
+    let mut checks = vec![];
+    for group in expected.chunk_by(|a, b| a.day == b.day && a.input == b.input) {
+        let parts = group.iter().map(|e| e.part).collect::<Vec<_>>();
+        let (day, input) = (group[0].day, &group[0].input);
+        let answers = find_day(day)
+            .with_context(|| format!("no solution for day {day}"))
+            .and_then(|solver| {
+                let input = source::load(day, input.to_str())?;
+                (solver.solve)(&input.raw, &parts)
+            });
+        match answers {
+            Ok(solved) => {
+                for (expected, actual) in group.iter().zip(solved.parts) {
+                    let actual = actual.answer;
+                    let outcome = if actual.to_string() == expected.answer {
+                        Outcome::Pass
+                    } else {
+                        Outcome::Mismatch {
+                            expected: expected.answer.clone(),
+                            actual,
+                        }
+                    };
+                    checks.push(Check {
+                        expected: expected.clone(),
+                        outcome,
+                    });
+                }
+            }
+            Err(e) => checks.extend(group.iter().map(|expected| Check {
+                expected: expected.clone(),
+                outcome: Outcome::Fail(format!("{}: {e:#}", ErrorKind::of(&e)).replace('\n', " ")),
+            })),
+        }
+    }
+    checks
+}
+
+/// 0 when everything passed, 1 if any answer was wrong and 2 if anything failed to run.
+pub fn exit_code(checks: &[Check]) -> u8 {
+    checks
+        .iter()
+        .map(|c| match c.outcome {
+            Outcome::Pass => 0,
+            Outcome::Mismatch { .. } => 1,
+            Outcome::Fail(_) => 2,
+        })
+        .max()
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::DAYS;
+
+    #[test]
+    fn examples() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/answers.toml");
+        let expected = load_answers(&path).expect("fixture answers should load");
+        for day in DAYS.iter().map(|d| d.day) {
+            assert!(
+                expected.iter().any(|e| e.day == day),
+                "day {day:02} has no example fixtures"
+            );
+        }
+        let failures = verify(&expected)
+            .into_iter()
+            .filter(|c| !matches!(c.outcome, Outcome::Pass))
+            .map(|c| {
+                let e = c.expected;
+                format!(
+                    "day {:02} part {} on {}: {}",
+                    e.day,
+                    e.part,
+                    e.input.display(),
+                    c.outcome
+                )
+            })
+            .collect::<Vec<_>>();
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}